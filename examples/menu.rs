@@ -26,9 +26,7 @@ fn main() {
                 handle_selectable_button_clicked,
             ),
         )
-        .register_type::<Settings>()
-        .register_type::<Theme>()
-        .register_type::<ThemeColor>()
+        .register_reflect_target_types::<(Settings, Theme, ThemeColor)>()
         .run();
 }
 
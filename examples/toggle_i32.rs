@@ -78,13 +78,9 @@ fn handle_input(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>) {
 
         // We need world access to perform reflection, so add a one-off command to perform the operation.
         commands.add(move |world: &mut World| {
-            // Read the current value via reflection
-            let current_value = target.read_value::<i32>(world);
-
-            // Update the value
-            let result = current_value.and_then(|current_value| {
-                let new_value = (current_value + amount).clamp(-10, 10);
-                target.set_value(world, new_value)
+            // Update the value via reflection
+            let result = target.update_value(world, |current_value: i32| {
+                (current_value + amount).clamp(-10, 10)
             });
 
             // Log the results of the operation
@@ -147,16 +147,14 @@ fn handle_interactions(
                 assert!(update_count <= 1);
             }
 
-            // read the colors from the trait
-            let mut read_count = 0; // used to assert that the closure wasn't called more than once
-            let result = reflect_trait_find_one::<ReflectInteractable, _>(
+            // read the colors from the trait, which errors if more than one component reflects it
+            let result = reflect_trait_expect_unique::<ReflectInteractable, _>(
                 world,
                 entity,
                 |reflect_value, reflect_trait| {
-                    reflect_trait.get(reflect_value).map(|r| {
-                        read_count += 1;
-                        r.get_colors(interaction)
-                    })
+                    reflect_trait
+                        .get(reflect_value)
+                        .map(|r| r.get_colors(interaction))
                 },
             );
             // Handle the result
@@ -173,8 +171,6 @@ fn handle_interactions(
                     error!("error handling click via trait: {:?}", err);
                 }
             }
-            // Assert that the closure did not run over more than one component
-            assert!(read_count <= 1);
         });
     }
 }
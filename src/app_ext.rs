@@ -0,0 +1,99 @@
+use bevy::{
+    prelude::*,
+    reflect::{GetTypeRegistration, TypeRegistry},
+};
+
+/// A tuple of reflected types that can be registered in one call. See
+/// [`ReflectUtilsAppExt::register_reflect_target_types`].
+///
+/// Implemented for tuples of up to 12 types.
+pub trait ReflectTypes {
+    /// Registers every type in the tuple.
+    fn register(type_registry: &mut TypeRegistry);
+}
+
+macro_rules! impl_reflect_types {
+    ($($type:ident),+) => {
+        impl<$($type: GetTypeRegistration),+> ReflectTypes for ($($type,)+) {
+            fn register(type_registry: &mut TypeRegistry) {
+                $(type_registry.register::<$type>();)+
+            }
+        }
+    };
+}
+
+impl_reflect_types!(T1);
+impl_reflect_types!(T1, T2);
+impl_reflect_types!(T1, T2, T3);
+impl_reflect_types!(T1, T2, T3, T4);
+impl_reflect_types!(T1, T2, T3, T4, T5);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_reflect_types!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Extension trait for [`App`] that registers the types used by reflect targets.
+///
+/// ```ignore
+/// // Same as calling `register_type` for each type
+/// app.register_reflect_target_types::<(Settings, Theme, ThemeColor)>();
+/// ```
+pub trait ReflectUtilsAppExt {
+    /// Registers every type in the tuple `T` in the `AppTypeRegistry`.
+    fn register_reflect_target_types<T: ReflectTypes>(&mut self) -> &mut Self;
+}
+
+impl ReflectUtilsAppExt for App {
+    fn register_reflect_target_types<T: ReflectTypes>(&mut self) -> &mut Self {
+        let app_type_registry = self.world().resource::<AppTypeRegistry>();
+        T::register(&mut app_type_registry.write());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+    }
+
+    #[derive(Reflect, Default)]
+    enum EnumA {
+        #[default]
+        A,
+        B,
+    }
+
+    #[test]
+    fn register_reflect_target_types_works() {
+        let mut app = App::new();
+        app.register_reflect_target_types::<(ResourceA, ComponentA, EnumA)>();
+
+        let type_registry = app.world().resource::<AppTypeRegistry>().read();
+        for type_id in [
+            TypeId::of::<ResourceA>(),
+            TypeId::of::<ComponentA>(),
+            TypeId::of::<EnumA>(),
+        ] {
+            assert!(type_registry.contains(type_id));
+        }
+        assert!(type_registry
+            .get_type_data::<ReflectResource>(TypeId::of::<ResourceA>())
+            .is_some());
+    }
+}
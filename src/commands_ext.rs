@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Extension trait for [`Commands`] that queues reflection operations on a [`ReflectTarget`].
+///
+/// Each method adds a one-off command that performs the operation once world access is available, and logs the
+/// result. Changes are logged at the `debug` level and errors at the `error` level.
+pub trait ReflectCommandsExt {
+    /// Queues setting the serialized value of the target field. See [`ReflectTarget::set_value_serialized`].
+    fn reflect_set_serialized(&mut self, target: ReflectTarget, value: impl Into<String>);
+
+    /// Queues toggling the enum variant of the target field. See [`ReflectTarget::toggle_reflect_enum`].
+    fn reflect_toggle_enum(&mut self, target: ReflectTarget, direction: EnumDirection);
+
+    /// Queues adding `amount` to the `i32` target field, clamping the result to the optional `min` and `max` values.
+    fn reflect_increment_i32(
+        &mut self,
+        target: ReflectTarget,
+        amount: i32,
+        min: Option<i32>,
+        max: Option<i32>,
+    );
+}
+
+impl ReflectCommandsExt for Commands<'_, '_> {
+    fn reflect_set_serialized(&mut self, target: ReflectTarget, value: impl Into<String>) {
+        let value = value.into();
+        self.add(move |world: &mut World| {
+            let result = target.set_value_serialized(world, &value);
+            log_set_result(&target, result);
+        });
+    }
+
+    fn reflect_toggle_enum(&mut self, target: ReflectTarget, direction: EnumDirection) {
+        self.add(move |world: &mut World| {
+            let result = target.toggle_reflect_enum(world, direction);
+            log_set_result(&target, result);
+        });
+    }
+
+    fn reflect_increment_i32(
+        &mut self,
+        target: ReflectTarget,
+        amount: i32,
+        min: Option<i32>,
+        max: Option<i32>,
+    ) {
+        self.add(move |world: &mut World| {
+            let result = target.read_value::<i32>(world).and_then(|value| {
+                let mut new_value = value.saturating_add(amount);
+                if let Some(min) = min {
+                    new_value = new_value.max(min);
+                }
+                if let Some(max) = max {
+                    new_value = new_value.min(max);
+                }
+                target.set_value(world, new_value)
+            });
+            log_set_result(&target, result);
+        });
+    }
+}
+
+/// Logs the result of a queued reflection operation.
+fn log_set_result(target: &ReflectTarget, result: ReflectSetResult) {
+    match result {
+        Ok(ReflectSetSuccess::Changed) => debug!("Reflect target {target:?} changed"),
+        Ok(ReflectSetSuccess::NoChanges) => debug!("Reflect target {target:?} not changed"),
+        Err(err) => error!("Reflect target {target:?} failed: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+
+    #[derive(Reflect, Default, PartialEq, Eq, Debug)]
+    enum EnumA {
+        #[default]
+        A,
+        B,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+        mode: EnumA,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<EnumA>();
+
+        world
+    }
+
+    /// Test utility that runs commands on a world.
+    fn run_with_commands(world: &mut World, cmds: impl FnOnce(&mut Commands)) {
+        let mut system_state: SystemState<Commands> = SystemState::new(world);
+        let mut commands = system_state.get_mut(world);
+
+        cmds(&mut commands);
+
+        system_state.apply(world);
+    }
+
+    #[test]
+    fn reflect_set_serialized_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        run_with_commands(&mut world, |commands| {
+            commands.reflect_set_serialized(target, "{\"i32\":5}");
+        });
+        assert_eq!(world.resource::<ResourceA>().value, 5);
+    }
+
+    #[test]
+    fn reflect_toggle_enum_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        run_with_commands(&mut world, |commands| {
+            commands.reflect_toggle_enum(target, EnumDirection::Forward);
+        });
+        assert_eq!(world.resource::<ResourceA>().mode, EnumA::B);
+    }
+
+    #[test]
+    fn reflect_increment_i32_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        run_with_commands(&mut world, |commands| {
+            commands.reflect_increment_i32(target.clone(), 3, None, None);
+        });
+        assert_eq!(world.resource::<ResourceA>().value, 3);
+
+        // Test the value is clamped to the maximum
+        run_with_commands(&mut world, |commands| {
+            commands.reflect_increment_i32(target, 3, None, Some(4));
+        });
+        assert_eq!(world.resource::<ResourceA>().value, 4);
+    }
+}
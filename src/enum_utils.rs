@@ -2,7 +2,7 @@ use std::any::TypeId;
 
 use bevy::{
     prelude::*,
-    reflect::{DynamicEnum, Enum, ReflectRef, TypeRegistry},
+    reflect::{DynamicEnum, Enum, ReflectRef, TypeRegistry, VariantInfo},
 };
 
 use crate::*;
@@ -18,6 +18,15 @@ pub enum NextEnumVariant {
     NoChanges,
 }
 
+/// The variant names of an enum and the index of its current variant, returned by `enum_variant_overview`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumOverview {
+    /// The names of all of the enum's variants, in declaration order.
+    pub names: Vec<String>,
+    /// The index of the current variant in `names`.
+    pub current_index: usize,
+}
+
 /// Utility helper that calls `reflect_component_read_enum_variant_name` from just the world.
 ///
 /// It saves you from having to pass in an `EntityRef` and `TypeRegistry` if you don't have them already.
@@ -25,8 +34,9 @@ pub fn reflect_component_read_enum_variant_name_from_world(
     world: &World,
     entity: Entity,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<String, ReflectError> {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
@@ -47,8 +57,9 @@ pub fn reflect_component_read_enum_variant_name(
     entity_ref: &EntityRef,
     type_registry: &TypeRegistry,
     component_type_id: TypeId,
-    field_path: &str,
+    field_path: impl AsRef<str>,
 ) -> Result<String, ReflectError> {
+    let field_path = field_path.as_ref();
     with_component_reflect_field(
         entity_ref,
         type_registry,
@@ -65,8 +76,9 @@ pub fn reflect_component_read_enum_variant_name(
 pub fn reflect_resource_read_enum_variant_name(
     world: &World,
     resource_type_id: TypeId,
-    field_path: &str,
+    field_path: impl AsRef<str>,
 ) -> Result<String, ReflectError> {
+    let field_path = field_path.as_ref();
     with_resource_reflect_field(world, resource_type_id, field_path, |field| {
         match field.reflect_ref() {
             ReflectRef::Enum(dyn_enum) => Ok(dyn_enum.variant_name().to_string()),
@@ -87,26 +99,42 @@ pub fn reflect_component_toggle_enum_variant(
     world: &mut World,
     component_type_id: TypeId,
     entity: Entity,
-    path: &str,
+    path: impl AsRef<str>,
+    direction: EnumDirection,
+    wrap: bool,
+) -> ReflectSetResult {
+    let path = path.as_ref();
+    reflect_component_toggle_enum_variant_filtered(
+        world,
+        component_type_id,
+        entity,
+        path,
+        direction,
+        wrap,
+        |_| false,
+    )
+}
+
+/// Toggles the enum variant of a field by its path on a component on an entity, skipping any variants for which
+/// `should_skip` returns `true`.
+///
+/// See `get_next_enum_variant_filtered` for more information.
+pub fn reflect_component_toggle_enum_variant_filtered(
+    world: &mut World,
+    component_type_id: TypeId,
+    entity: Entity,
+    path: impl AsRef<str>,
     direction: EnumDirection,
     wrap: bool,
+    should_skip: impl Fn(&VariantInfo) -> bool,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
     with_reflect_component_field_mut_world(world, component_type_id, entity, path, |field| {
-        if let ReflectRef::Enum(dyn_enum) = field.reflect_ref() {
-            let next_variant = get_next_enum_variant(dyn_enum, &type_registry, direction, wrap)?;
-            match next_variant {
-                NextEnumVariant::Ok(next_value) => {
-                    field.apply(next_value.as_reflect());
-                    Ok(ReflectSetSuccess::Changed)
-                }
-                NextEnumVariant::NoChanges => Ok(ReflectSetSuccess::NoChanges),
-            }
-        } else {
-            Err(ReflectError::InvalidDowncast)
-        }
+        toggle_enum_field(field, &type_registry, direction, wrap, should_skip)
+            .map(|(result, _)| result)
     })?
 }
 
@@ -121,29 +149,108 @@ pub fn reflect_component_toggle_enum_variant(
 pub fn reflect_resource_toggle_enum_variant(
     world: &mut World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     direction: EnumDirection,
     wrap: bool,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
+    reflect_resource_toggle_enum_variant_filtered(
+        world,
+        resource_type_id,
+        path,
+        direction,
+        wrap,
+        |_| false,
+    )
+}
+
+/// Toggles the enum variant of a field by its path on a resource, skipping any variants for which `should_skip`
+/// returns `true`.
+///
+/// See `get_next_enum_variant_filtered` for more information.
+pub fn reflect_resource_toggle_enum_variant_filtered(
+    world: &mut World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    direction: EnumDirection,
+    wrap: bool,
+    should_skip: impl Fn(&VariantInfo) -> bool,
+) -> ReflectSetResult {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
     with_resource_reflect_field_mut(world, resource_type_id, path, |field| {
-        if let ReflectRef::Enum(dyn_enum) = field.reflect_ref() {
-            let next_variant = get_next_enum_variant(dyn_enum, &type_registry, direction, wrap)?;
-            match next_variant {
-                NextEnumVariant::Ok(next_value) => {
-                    field.apply(next_value.as_reflect());
-                    Ok(ReflectSetSuccess::Changed)
-                }
-                NextEnumVariant::NoChanges => Ok(ReflectSetSuccess::NoChanges),
-            }
-        } else {
-            Err(ReflectError::InvalidDowncast)
-        }
+        toggle_enum_field(field, &type_registry, direction, wrap, should_skip)
+            .map(|(result, _)| result)
+    })?
+}
+
+/// Toggles the enum variant of a component field like `reflect_component_toggle_enum_variant`, also returning the name
+/// of the resulting variant.
+///
+/// This saves reading the variant name again after toggling, e.g. to update a label.
+pub fn reflect_component_toggle_enum_variant_named(
+    world: &mut World,
+    component_type_id: TypeId,
+    entity: Entity,
+    path: impl AsRef<str>,
+    direction: EnumDirection,
+    wrap: bool,
+) -> Result<(ReflectSetSuccess, String), ReflectError> {
+    let path = path.as_ref();
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    with_reflect_component_field_mut_world(world, component_type_id, entity, path, |field| {
+        toggle_enum_field(field, &type_registry, direction, wrap, |_| false)
     })?
 }
 
+/// Toggles the enum variant of a resource field like `reflect_resource_toggle_enum_variant`, also returning the name
+/// of the resulting variant.
+///
+/// This saves reading the variant name again after toggling, e.g. to update a label.
+pub fn reflect_resource_toggle_enum_variant_named(
+    world: &mut World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    direction: EnumDirection,
+    wrap: bool,
+) -> Result<(ReflectSetSuccess, String), ReflectError> {
+    let path = path.as_ref();
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    with_resource_reflect_field_mut(world, resource_type_id, path, |field| {
+        toggle_enum_field(field, &type_registry, direction, wrap, |_| false)
+    })?
+}
+
+/// Toggles the variant of a reflected enum field, returning the result and the name of the resulting variant.
+fn toggle_enum_field(
+    field: &mut dyn Reflect,
+    type_registry: &TypeRegistry,
+    direction: EnumDirection,
+    wrap: bool,
+    should_skip: impl Fn(&VariantInfo) -> bool,
+) -> Result<(ReflectSetSuccess, String), ReflectError> {
+    let ReflectRef::Enum(dyn_enum) = field.reflect_ref() else {
+        return Err(ReflectError::InvalidDowncast);
+    };
+    match get_next_enum_variant_filtered(dyn_enum, type_registry, direction, wrap, should_skip)? {
+        NextEnumVariant::Ok(next_value) => {
+            let variant_name = next_value.variant_name().to_string();
+            field.apply(next_value.as_reflect());
+            Ok((ReflectSetSuccess::Changed, variant_name))
+        }
+        NextEnumVariant::NoChanges => Ok((
+            ReflectSetSuccess::NoChanges,
+            dyn_enum.variant_name().to_string(),
+        )),
+    }
+}
+
 /// Utility that returns the next index in a range in a specified direction, with optional "wrap-around" functionality
 /// via the `wrap` argument.
 ///
@@ -185,17 +292,52 @@ pub fn get_next_enum_variant(
     type_registry: &TypeRegistry,
     direction: EnumDirection,
     wrap: bool,
+) -> Result<NextEnumVariant, ReflectError> {
+    get_next_enum_variant_filtered(dyn_enum, type_registry, direction, wrap, |_| false)
+}
+
+/// Returns the next enum variant in the specified direction, skipping any variants for which `should_skip` returns
+/// `true`.
+///
+/// Keeps stepping in the specified direction until it finds a variant that is not skipped. Returns
+/// `NextEnumVariant::NoChanges` if it reaches the end of the list of variants without wrapping, or arrives back at the
+/// current variant.
+pub fn get_next_enum_variant_filtered(
+    dyn_enum: &dyn Enum,
+    type_registry: &TypeRegistry,
+    direction: EnumDirection,
+    wrap: bool,
+    should_skip: impl Fn(&VariantInfo) -> bool,
 ) -> Result<NextEnumVariant, ReflectError> {
     let index = dyn_enum.variant_index();
-    let type_info = dyn_enum.get_represented_type_info().unwrap();
+    // NOTE: Dynamic enums that were not created from a concrete type have no type info to read the variants from.
+    let type_info = dyn_enum
+        .get_represented_type_info()
+        .ok_or(ReflectError::InvalidDowncast)?;
 
     if let bevy::reflect::TypeInfo::Enum(enum_info) = type_info {
         // Get the next enum variant in the specified direction.
         // NOTE: `new_variant` will be `None` if the result is unchanged, wrapping is disabled, and it's at the end of
         // the list, or the enum does not contain a variant at the next index. The last should never happen.
         let n_variants = enum_info.iter().count();
-        let new_variant = get_next_index_in_direction(index, n_variants, direction, wrap)
-            .and_then(|new_index| enum_info.variant_at(new_index));
+        if n_variants == 0 {
+            return Ok(NextEnumVariant::NoChanges);
+        }
+        let mut new_variant = None;
+        let mut next_index = index;
+        for _ in 0..n_variants {
+            match get_next_index_in_direction(next_index, n_variants, direction, wrap) {
+                Some(new_index) if new_index != index => {
+                    next_index = new_index;
+                    let variant = enum_info.variant_at(new_index);
+                    if variant.is_some_and(|variant| !should_skip(variant)) {
+                        new_variant = variant;
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
 
         match new_variant {
             Some(new_variant_info) => {
@@ -211,9 +353,26 @@ pub fn get_next_enum_variant(
     }
 }
 
+/// Returns the names of all variants of an enum and the index of its current variant, e.g. for rendering a selector.
+///
+/// Returns `ReflectError::InvalidDowncast` if the enum does not represent a type with enum type info.
+pub fn enum_variant_overview(dyn_enum: &dyn Enum) -> Result<EnumOverview, ReflectError> {
+    let Some(bevy::reflect::TypeInfo::Enum(enum_info)) = dyn_enum.get_represented_type_info()
+    else {
+        return Err(ReflectError::InvalidDowncast);
+    };
+    Ok(EnumOverview {
+        names: enum_info
+            .iter()
+            .map(|variant| variant.name().to_string())
+            .collect(),
+        current_index: dyn_enum.variant_index(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use bevy::ecs::system::SystemState;
+    use bevy::{ecs::system::SystemState, reflect::DynamicVariant};
 
     use super::*;
 
@@ -238,6 +397,20 @@ mod tests {
         value2: EnumA,
     }
 
+    #[derive(Reflect, Default, PartialEq, Eq, Debug)]
+    enum EnumB {
+        #[default]
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Resource, Reflect, Default, Debug)]
+    #[reflect(Resource)]
+    struct ResourceB {
+        value: EnumB,
+    }
+
     /// Test utility that creates a new world and registers the test types
     fn create_world() -> World {
         let mut world = World::new();
@@ -247,6 +420,8 @@ mod tests {
         type_registry.write().register::<ComponentA>();
         type_registry.write().register::<ResourceA>();
         type_registry.write().register::<EnumA>();
+        type_registry.write().register::<ResourceB>();
+        type_registry.write().register::<EnumB>();
 
         world
     }
@@ -263,6 +438,34 @@ mod tests {
         result
     }
 
+    #[test]
+    fn get_next_enum_variant_errors_without_type_info() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        // A dynamic enum that does not represent a concrete type has no variants to step through
+        let dyn_enum = DynamicEnum::new("A", DynamicVariant::Unit);
+        assert!(matches!(
+            get_next_enum_variant(&dyn_enum, &type_registry, EnumDirection::Forward, true),
+            Err(ReflectError::InvalidDowncast)
+        ));
+    }
+
+    #[test]
+    fn enum_variant_overview_works() {
+        assert_eq!(
+            enum_variant_overview(&EnumB::B),
+            Ok(EnumOverview {
+                names: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+                current_index: 1,
+            })
+        );
+        assert_eq!(
+            enum_variant_overview(&EnumB::C).map(|overview| overview.current_index),
+            Ok(2)
+        );
+    }
+
     #[test]
     fn reflect_component_read_enum_variant_name_works() {
         let mut world = create_world();
@@ -403,6 +606,61 @@ mod tests {
         assert_eq!(world.resource::<ResourceA>().value1, EnumA::A);
     }
 
+    #[test]
+    fn reflect_resource_toggle_enum_variant_filtered_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceB { value: EnumB::A });
+        let skip_b = |variant: &VariantInfo| variant.name() == "B";
+
+        // Test toggling forward skips `EnumB::B`
+        let result = reflect_resource_toggle_enum_variant_filtered(
+            &mut world,
+            TypeId::of::<ResourceB>(),
+            "value",
+            EnumDirection::Forward,
+            false,
+            skip_b,
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        assert_eq!(world.resource::<ResourceB>().value, EnumB::C);
+
+        // Test toggling forward without wrapping at the end of the list does nothing
+        let result = reflect_resource_toggle_enum_variant_filtered(
+            &mut world,
+            TypeId::of::<ResourceB>(),
+            "value",
+            EnumDirection::Forward,
+            false,
+            skip_b,
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::NoChanges));
+        assert_eq!(world.resource::<ResourceB>().value, EnumB::C);
+
+        // Test toggling backward skips `EnumB::B`
+        let result = reflect_resource_toggle_enum_variant_filtered(
+            &mut world,
+            TypeId::of::<ResourceB>(),
+            "value",
+            EnumDirection::Backward,
+            false,
+            skip_b,
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        assert_eq!(world.resource::<ResourceB>().value, EnumB::A);
+
+        // Test toggling when every other variant is skipped does nothing, even with wrapping
+        let result = reflect_resource_toggle_enum_variant_filtered(
+            &mut world,
+            TypeId::of::<ResourceB>(),
+            "value",
+            EnumDirection::Forward,
+            true,
+            |variant| variant.name() != "A",
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::NoChanges));
+        assert_eq!(world.resource::<ResourceB>().value, EnumB::A);
+    }
+
     #[test]
     fn reflect_resource_read_enum_variant_name_works() {
         let mut world = create_world();
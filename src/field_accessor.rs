@@ -0,0 +1,249 @@
+use bevy::{
+    prelude::*,
+    reflect::{FromType, ParsedPath, TypeRegistry},
+};
+
+use crate::*;
+
+/// Trait for opaque wrapper types whose inner value should be reachable by reflection paths.
+///
+/// Implement it and register the type data with `#[reflect(FieldAccessor)]` (or `#[reflect_value(FieldAccessor)]`),
+/// so paths like `"wrapped.volume"` continue into the inner value, and targets with `auto_deref` enabled can read the
+/// inner value directly. Implement `reflect_inner_mut` too for those targets to set it. See [`ReflectFieldAccessor`].
+pub trait FieldAccessor {
+    /// Returns the inner value that path traversal should continue into.
+    fn reflect_inner(&self) -> &dyn Reflect;
+
+    /// Returns the inner value mutably, or `None` if the wrapper is readonly, which it is by default.
+    fn reflect_inner_mut(&mut self) -> Option<&mut dyn Reflect> {
+        None
+    }
+}
+
+/// Type data telling path traversal how to get the inner reflected value of a wrapper type.
+#[derive(Clone)]
+pub struct ReflectFieldAccessor {
+    get: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    get_mut: fn(&mut dyn Reflect) -> Option<&mut dyn Reflect>,
+}
+
+impl ReflectFieldAccessor {
+    /// Returns the inner value of `value`, or `None` if `value` is not the type this data was registered for.
+    pub fn get<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.get)(value)
+    }
+
+    /// Returns the inner value of `value` mutably, or `None` if `value` is not the type this data was registered for or
+    /// the wrapper is readonly.
+    pub fn get_mut<'a>(&self, value: &'a mut dyn Reflect) -> Option<&'a mut dyn Reflect> {
+        (self.get_mut)(value)
+    }
+}
+
+impl<T: FieldAccessor + Reflect> FromType<T> for ReflectFieldAccessor {
+    fn from_type() -> Self {
+        Self {
+            get: get_inner::<T>,
+            get_mut: get_inner_mut::<T>,
+        }
+    }
+}
+
+fn get_inner<T: FieldAccessor + Reflect>(value: &dyn Reflect) -> Option<&dyn Reflect> {
+    value.downcast_ref::<T>().map(T::reflect_inner)
+}
+
+fn get_inner_mut<T: FieldAccessor + Reflect>(value: &mut dyn Reflect) -> Option<&mut dyn Reflect> {
+    value.downcast_mut::<T>().and_then(T::reflect_inner_mut)
+}
+
+/// Resolves a path from `root` like `reflect_path`, but continues into the inner value of any type along the way
+/// that has `ReflectFieldAccessor` type data.
+pub fn reflect_path_with_accessors<'r>(
+    type_registry: &TypeRegistry,
+    root: &'r dyn Reflect,
+    path: &str,
+) -> Result<&'r dyn Reflect, ReflectError> {
+    // Only walk the path segment by segment if it does not resolve directly
+    let err = match root.reflect_path(path) {
+        Ok(value) => return Ok(value),
+        Err(err) => err.to_string(),
+    };
+
+    let parsed_path =
+        ParsedPath::parse(path).map_err(|err| ReflectError::ReflectPath(err.to_string()))?;
+    let mut current = root;
+    for offset_access in parsed_path.0.iter() {
+        let segment = ParsedPath(vec![offset_access.clone()]);
+        current = match current.reflect_path(&segment) {
+            Ok(next) => next,
+            Err(_) => current
+                .get_represented_type_info()
+                .and_then(|type_info| {
+                    type_registry.get_type_data::<ReflectFieldAccessor>(type_info.type_id())
+                })
+                .and_then(|accessor| accessor.get(current))
+                .and_then(|inner| inner.reflect_path(&segment).ok())
+                .ok_or_else(|| reflect_path_error(root, path, &err))?,
+        };
+    }
+
+    Ok(current)
+}
+
+/// Follows `ReflectFieldAccessor` type data from `value` until it reaches a value of type `T`.
+///
+/// Returns `value` itself if it is already a `T`, or `None` if no accessor leads to a `T`.
+pub fn reflect_auto_deref<'r, T: Reflect>(
+    type_registry: &TypeRegistry,
+    value: &'r dyn Reflect,
+) -> Option<&'r T> {
+    if let Some(value) = value.downcast_ref::<T>() {
+        return Some(value);
+    }
+    let type_id = value.get_represented_type_info()?.type_id();
+    let accessor = type_registry.get_type_data::<ReflectFieldAccessor>(type_id)?;
+    reflect_auto_deref(type_registry, accessor.get(value)?)
+}
+
+/// Mutable version of `reflect_auto_deref`.
+pub fn reflect_auto_deref_mut<'r, T: Reflect>(
+    type_registry: &TypeRegistry,
+    value: &'r mut dyn Reflect,
+) -> Option<&'r mut dyn Reflect> {
+    if value.is::<T>() {
+        return Some(value);
+    }
+    let type_id = value.get_represented_type_info()?.type_id();
+    let accessor = type_registry.get_type_data::<ReflectFieldAccessor>(type_id)?;
+    reflect_auto_deref_mut::<T>(type_registry, accessor.get_mut(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::*;
+
+    #[derive(Reflect, Default, Clone)]
+    struct Inner {
+        volume: u32,
+    }
+
+    // NOTE: Reflected as an opaque value, so paths can not reach `Inner` without the accessor
+    #[derive(Reflect, Default, Clone)]
+    #[reflect_value(Default, FieldAccessor)]
+    struct Wrapper(Inner);
+
+    impl FieldAccessor for Wrapper {
+        fn reflect_inner(&self) -> &dyn Reflect {
+            &self.0
+        }
+
+        fn reflect_inner_mut(&mut self) -> Option<&mut dyn Reflect> {
+            Some(&mut self.0)
+        }
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        wrapped: Wrapper,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        wrapped: Wrapper,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<ComponentA>();
+        type_registry.write().register::<Wrapper>();
+
+        world
+    }
+
+    #[test]
+    fn reflect_resource_read_path_through_accessor_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            wrapped: Wrapper(Inner { volume: 4 }),
+        });
+
+        assert_eq!(
+            reflect_resource_read_path::<u32>(&world, TypeId::of::<ResourceA>(), "wrapped.volume"),
+            Ok(4)
+        );
+
+        // Test borrowing the field resolves the same path
+        let field_ref =
+            reflect_resource_read_path_ref(&world, TypeId::of::<ResourceA>(), "wrapped.volume");
+        assert_eq!(field_ref.unwrap().get::<u32>(), Some(&4));
+
+        // Test paths that do not exist on the inner value still fail
+        assert!(matches!(
+            reflect_resource_read_path::<u32>(&world, TypeId::of::<ResourceA>(), "wrapped.missing"),
+            Err(ReflectError::ReflectPath(_))
+        ));
+    }
+
+    #[test]
+    fn auto_deref_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            wrapped: Wrapper(Inner { volume: 4 }),
+        });
+        let target = ReflectTarget::new_resource::<ResourceA>("wrapped").with_auto_deref();
+
+        // Test the wrapper is followed to read and set the inner value directly
+        assert_eq!(target.read_value::<Inner>(&mut world).unwrap().volume, 4);
+        assert_eq!(
+            target.set_value(&mut world, Inner { volume: 6 }),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().wrapped.0.volume, 6);
+
+        // Test the wrapper itself can still be read
+        assert!(target.read_value::<Wrapper>(&mut world).is_ok());
+
+        // Test the error when no accessor leads to the type
+        assert_eq!(
+            target.read_value::<u32>(&mut world),
+            Err(ReflectError::InvalidDowncast)
+        );
+
+        // Test the wrapper is not followed unless `auto_deref` is enabled
+        let target = ReflectTarget::new_resource::<ResourceA>("wrapped");
+        assert_eq!(
+            target
+                .read_value::<Inner>(&mut world)
+                .map(|inner| inner.volume),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn auto_deref_component_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn(ComponentA {
+                wrapped: Wrapper(Inner { volume: 1 }),
+            })
+            .id();
+        let target =
+            ReflectTarget::new_component::<ComponentA>(entity, "wrapped").with_auto_deref();
+
+        assert_eq!(
+            target.set_value(&mut world, Inner { volume: 2 }),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(target.read_value::<Inner>(&mut world).unwrap().volume, 2);
+    }
+}
@@ -0,0 +1,275 @@
+use std::{any::TypeId, ops::RangeInclusive};
+
+use bevy::{
+    prelude::*,
+    reflect::{attributes::CustomAttributes, TypeInfo, TypeRegistry},
+};
+use serde::Serialize;
+
+use crate::*;
+
+/// Machine-readable description of the fields of a resource, e.g. for generating forms.
+///
+/// Returned by `reflect_resource_form_schema`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FormSchema {
+    /// The type path of the resource.
+    pub type_path: String,
+    /// The fields of the resource. Fields of nested structs are flattened into their own entries.
+    pub fields: Vec<FormField>,
+}
+
+/// Description of a single field in a [`FormSchema`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FormField {
+    /// The path to the field from the root of the resource, e.g. `"audio.volume"`.
+    pub path: String,
+    /// The type path of the field.
+    pub type_path: String,
+    /// The kind of input the field maps to.
+    pub kind: FormFieldKind,
+}
+
+/// The kind of input a [`FormField`] maps to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FormFieldKind {
+    /// A boolean.
+    Bool,
+    /// A numeric primitive, with the range from its `RangeInclusive` attribute if it has one.
+    Number { range: Option<RangeInclusive<f64>> },
+    /// A `String`.
+    String,
+    /// An enum, with the names of all of its variants.
+    Enum { variants: Vec<String> },
+    /// Any other type.
+    Other,
+}
+
+/// Exports a [`FormSchema`] describing every field of a resource type.
+///
+/// Fields that are structs or tuple structs with a type registration are walked recursively, so only their leaf fields
+/// are included.
+pub fn reflect_resource_form_schema(
+    world: &World,
+    resource_type_id: TypeId,
+) -> Result<FormSchema, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let type_info = type_registry
+        .get_type_info(resource_type_id)
+        .ok_or(ReflectError::TypeRegistrationNotFound)?;
+
+    let mut fields = Vec::new();
+    collect_form_fields(&type_registry, type_info, "", &mut fields);
+
+    Ok(FormSchema {
+        type_path: type_info.type_path().to_string(),
+        fields,
+    })
+}
+
+/// Builds a [`ReflectTarget`] for every field in a schema, bound to the resource or component described by `owner`,
+/// e.g. to bind the controls generated from a schema back to the fields.
+pub fn reflect_targets_from_schema(schema: &FormSchema, owner: ReflectKind) -> Vec<ReflectTarget> {
+    schema
+        .fields
+        .iter()
+        .map(|field| ReflectTarget::new(owner, field.path.clone()))
+        .collect()
+}
+
+/// Recursively collects the leaf fields of a struct or tuple struct.
+fn collect_form_fields(
+    type_registry: &TypeRegistry,
+    type_info: &TypeInfo,
+    path: &str,
+    fields: &mut Vec<FormField>,
+) {
+    match type_info {
+        TypeInfo::Struct(struct_info) => {
+            for field in struct_info.iter() {
+                add_form_field(
+                    type_registry,
+                    join_field_path(path, field.name()),
+                    field.type_id(),
+                    field.type_path(),
+                    field.custom_attributes(),
+                    fields,
+                );
+            }
+        }
+        TypeInfo::TupleStruct(tuple_struct_info) => {
+            for field in tuple_struct_info.iter() {
+                add_form_field(
+                    type_registry,
+                    join_field_path(path, &field.index().to_string()),
+                    field.type_id(),
+                    field.type_path(),
+                    field.custom_attributes(),
+                    fields,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Adds a field to the schema, or walks its fields if it is a registered struct or tuple struct.
+fn add_form_field(
+    type_registry: &TypeRegistry,
+    path: String,
+    type_id: TypeId,
+    type_path: &str,
+    attributes: &CustomAttributes,
+    fields: &mut Vec<FormField>,
+) {
+    let field_type_info = type_registry.get_type_info(type_id);
+    if let Some(field_type_info @ (TypeInfo::Struct(_) | TypeInfo::TupleStruct(_))) =
+        field_type_info
+    {
+        collect_form_fields(type_registry, field_type_info, &path, fields);
+        return;
+    }
+
+    let kind = if type_id == TypeId::of::<bool>() {
+        FormFieldKind::Bool
+    } else if type_id == TypeId::of::<String>() {
+        FormFieldKind::String
+    } else if is_reflect_number_type(type_id) {
+        FormFieldKind::Number {
+            range: reflect_range_attribute(attributes),
+        }
+    } else if let Some(TypeInfo::Enum(enum_info)) = field_type_info {
+        FormFieldKind::Enum {
+            variants: enum_info
+                .iter()
+                .map(|variant| variant.name().to_string())
+                .collect(),
+        }
+    } else {
+        FormFieldKind::Other
+    };
+
+    fields.push(FormField {
+        path,
+        type_path: type_path.to_string(),
+        kind,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Default)]
+    enum Theme {
+        #[default]
+        Light,
+        Dark,
+        Custom(u32),
+    }
+
+    #[derive(Reflect, Default)]
+    struct AudioSettings {
+        #[reflect(@0.0..=1.0_f32)]
+        volume: f32,
+        muted: bool,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Settings {
+        name: String,
+        theme: Theme,
+        audio: AudioSettings,
+        count: u32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct NonRegisteredResource;
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<Settings>();
+
+        world
+    }
+
+    #[test]
+    fn reflect_resource_form_schema_works() {
+        let world = create_world();
+
+        let schema = reflect_resource_form_schema(&world, TypeId::of::<Settings>()).unwrap();
+        assert_eq!(schema.type_path, Settings::type_path());
+        assert_eq!(
+            schema.fields,
+            vec![
+                FormField {
+                    path: "name".to_string(),
+                    type_path: String::type_path().to_string(),
+                    kind: FormFieldKind::String,
+                },
+                FormField {
+                    path: "theme".to_string(),
+                    type_path: Theme::type_path().to_string(),
+                    kind: FormFieldKind::Enum {
+                        variants: vec![
+                            "Light".to_string(),
+                            "Dark".to_string(),
+                            "Custom".to_string()
+                        ],
+                    },
+                },
+                FormField {
+                    path: "audio.volume".to_string(),
+                    type_path: "f32".to_string(),
+                    kind: FormFieldKind::Number {
+                        range: Some(0.0..=1.0),
+                    },
+                },
+                FormField {
+                    path: "audio.muted".to_string(),
+                    type_path: "bool".to_string(),
+                    kind: FormFieldKind::Bool,
+                },
+                FormField {
+                    path: "count".to_string(),
+                    type_path: "u32".to_string(),
+                    kind: FormFieldKind::Number { range: None },
+                },
+            ]
+        );
+
+        // Test the error when the type is not registered
+        assert_eq!(
+            reflect_resource_form_schema(&world, TypeId::of::<NonRegisteredResource>()),
+            Err(ReflectError::TypeRegistrationNotFound)
+        );
+    }
+
+    #[test]
+    fn reflect_targets_from_schema_works() {
+        let mut world = create_world();
+        world.init_resource::<Settings>();
+
+        let schema = reflect_resource_form_schema(&world, TypeId::of::<Settings>()).unwrap();
+        let owner = ReflectKind::Resource(TypeId::of::<Settings>());
+        let targets = reflect_targets_from_schema(&schema, owner);
+        assert_eq!(
+            targets,
+            ["name", "theme", "audio.volume", "audio.muted", "count"]
+                .map(ReflectTarget::new_resource::<Settings>)
+                .to_vec()
+        );
+
+        // Test the targets resolve to the fields
+        assert!(targets.iter().all(|target| target.owner_exists(&world)));
+        assert_eq!(targets[2].read_value::<f32>(&mut world), Ok(0.0));
+    }
+}
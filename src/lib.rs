@@ -1,12 +1,29 @@
+mod app_ext;
+mod commands_ext;
 mod enum_utils;
+mod field_accessor;
+mod form_schema;
+mod logging;
+mod macros;
+mod numeric;
+mod reflect_cached_target;
 mod reflect_component;
+mod reflect_descendant_target;
+mod reflect_probe;
+mod reflect_reader;
 mod reflect_resource;
+mod reflect_scene;
 mod reflect_target;
 mod reflect_trait;
+mod reflect_watch;
 mod shared;
 mod types;
+mod widget_state;
+mod world_ext;
 
 pub use crate::{
-    enum_utils::*, reflect_component::*, reflect_resource::*, reflect_target::*, reflect_trait::*,
-    shared::*, types::*,
+    app_ext::*, commands_ext::*, enum_utils::*, field_accessor::*, form_schema::*, logging::*,
+    numeric::*, reflect_cached_target::*, reflect_component::*, reflect_descendant_target::*,
+    reflect_probe::*, reflect_reader::*, reflect_resource::*, reflect_scene::*, reflect_target::*,
+    reflect_trait::*, reflect_watch::*, shared::*, types::*, widget_state::*, world_ext::*,
 };
@@ -0,0 +1,94 @@
+use bevy::{log::Level, prelude::*};
+
+use crate::*;
+
+/// Configures the log levels used by [`ReflectTarget::set_value_serialized_logged`].
+///
+/// Insert it as a resource to override the defaults, which are `INFO` for changes, `WARN` for no changes and `ERROR`
+/// for errors.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReflectLogLevel {
+    /// The level used when the value changed.
+    pub changed: Level,
+    /// The level used when the value already matched.
+    pub no_changes: Level,
+    /// The level used when setting the value failed.
+    pub error: Level,
+}
+
+impl Default for ReflectLogLevel {
+    fn default() -> Self {
+        Self {
+            changed: Level::INFO,
+            no_changes: Level::WARN,
+            error: Level::ERROR,
+        }
+    }
+}
+
+impl ReflectLogLevel {
+    /// Logs the result of setting a value, prefixed with `context`.
+    pub fn log_set_result(&self, context: &str, result: &ReflectSetResult) {
+        match result {
+            Ok(ReflectSetSuccess::Changed) => log_at(self.changed, &format!("{context}: changed")),
+            Ok(ReflectSetSuccess::NoChanges) => {
+                log_at(self.no_changes, &format!("{context}: no changes"))
+            }
+            Err(err) => log_at(self.error, &format!("{context}: {err}")),
+        }
+    }
+}
+
+/// Logs a message at a level only known at runtime.
+fn log_at(level: Level, message: &str) {
+    match level {
+        Level::TRACE => trace!("{message}"),
+        Level::DEBUG => debug!("{message}"),
+        Level::INFO => info!("{message}"),
+        Level::WARN => warn!("{message}"),
+        _ => error!("{message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+
+        world
+    }
+
+    #[test]
+    fn set_value_serialized_logged_matches_unlogged() {
+        let mut logged_world = create_world();
+        logged_world.init_resource::<ResourceA>();
+        logged_world.insert_resource(ReflectLogLevel {
+            changed: Level::DEBUG,
+            ..default()
+        });
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        for value in ["{\"i32\":2}", "{\"i32\":2}", "{\"u32\":2}"] {
+            assert_eq!(
+                target.set_value_serialized_logged(&mut logged_world, value, "Set value"),
+                target.set_value_serialized(&mut world, value)
+            );
+        }
+        assert_eq!(logged_world.resource::<ResourceA>().value, 2);
+    }
+}
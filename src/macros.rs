@@ -0,0 +1,95 @@
+/// Constructs a [`ReflectTarget`](crate::ReflectTarget) from a type and a dotted field path.
+///
+/// ```ignore
+/// // Same as `ReflectTarget::new_resource::<Settings>("theme.0")`
+/// let target = reflect_target!(Settings::theme.0);
+///
+/// // Same as `ReflectTarget::new_component::<ExampleComponent>(entity, "value")`
+/// let target = reflect_target!(entity, ExampleComponent::value);
+/// ```
+///
+/// The type must be a single identifier in scope, and the path may only contain field names and tuple indices
+/// separated by dots.
+#[macro_export]
+macro_rules! reflect_target {
+    ($type:ident :: $first:ident $(. $rest:tt)*) => {
+        $crate::ReflectTarget::new_resource::<$type>(
+            concat!(stringify!($first) $(, ".", stringify!($rest))*)
+        )
+    };
+    ($entity:expr, $type:ident :: $first:ident $(. $rest:tt)*) => {
+        $crate::ReflectTarget::new_component::<$type>(
+            $entity,
+            concat!(stringify!($first) $(, ".", stringify!($rest))*),
+        )
+    };
+}
+
+/// Enters a `trace` level span until the end of the enclosing scope when the `trace` feature is enabled.
+///
+/// Expands to nothing otherwise, so the span fields are never evaluated and there is no overhead.
+macro_rules! reflect_span {
+    ($($args:tt)*) => {
+        #[cfg(feature = "trace")]
+        let _span = ::bevy::log::trace_span!($($args)*).entered();
+    };
+}
+
+pub(crate) use reflect_span;
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::*;
+
+    #[derive(Reflect, Default)]
+    struct Range {
+        min: u32,
+        max: u32,
+    }
+
+    #[derive(Reflect, Default)]
+    struct InnerStruct {
+        value: (u32, Range),
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: u32,
+        inner: InnerStruct,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: u32,
+        inner: InnerStruct,
+    }
+
+    #[test]
+    fn reflect_target_resource_works() {
+        assert_eq!(
+            reflect_target!(ResourceA::value),
+            ReflectTarget::new_resource::<ResourceA>("value")
+        );
+        assert_eq!(
+            reflect_target!(ResourceA::inner.value.1.max),
+            ReflectTarget::new_resource::<ResourceA>("inner.value.1.max")
+        );
+    }
+
+    #[test]
+    fn reflect_target_component_works() {
+        let entity = Entity::from_raw(1);
+        assert_eq!(
+            reflect_target!(entity, ComponentA::value),
+            ReflectTarget::new_component::<ComponentA>(entity, "value")
+        );
+        assert_eq!(
+            reflect_target!(Entity::PLACEHOLDER, ComponentA::inner.value.0),
+            ReflectTarget::new_component::<ComponentA>(Entity::PLACEHOLDER, "inner.value.0")
+        );
+    }
+}
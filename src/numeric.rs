@@ -0,0 +1,243 @@
+use std::{any::TypeId, ops::RangeInclusive};
+
+use bevy::{prelude::*, reflect::attributes::CustomAttributes};
+
+use crate::ReflectError;
+
+/// Utility that reads a reflected numeric primitive as an `f64`.
+///
+/// Supports `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `f32` and `f64`. Returns `None` for any other type.
+///
+/// NOTE: `i64` and `u64` values outside of the range `f64` can represent exactly are rounded.
+pub fn reflect_number_as_f64(value: &dyn Reflect) -> Option<f64> {
+    value
+        .downcast_ref::<f64>()
+        .copied()
+        .or_else(|| value.downcast_ref::<f32>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<i8>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<i16>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<i32>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<i64>().map(|v| *v as f64))
+        .or_else(|| value.downcast_ref::<u8>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<u16>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<u32>().map(|v| f64::from(*v)))
+        .or_else(|| value.downcast_ref::<u64>().map(|v| *v as f64))
+}
+
+/// Utility that converts an `f64` into a boxed value of the numeric primitive type with the given `TypeId`.
+///
+/// Supports the same types as `reflect_number_as_f64`. Returns `None` for any other type.
+///
+/// Narrowing follows the rules of `as` casts:
+/// - Integer types truncate towards zero, e.g. `1.9` becomes `1` and `-1.9` becomes `-1`.
+/// - Values outside of an integer type's range saturate to its minimum or maximum value, and `NaN` becomes `0`.
+/// - `f32` rounds to the nearest representable value.
+pub fn reflect_number_from_f64(type_id: TypeId, value: f64) -> Option<Box<dyn Reflect>> {
+    if type_id == TypeId::of::<f64>() {
+        Some(Box::new(value))
+    } else if type_id == TypeId::of::<f32>() {
+        Some(Box::new(value as f32))
+    } else if type_id == TypeId::of::<i8>() {
+        Some(Box::new(value as i8))
+    } else if type_id == TypeId::of::<i16>() {
+        Some(Box::new(value as i16))
+    } else if type_id == TypeId::of::<i32>() {
+        Some(Box::new(value as i32))
+    } else if type_id == TypeId::of::<i64>() {
+        Some(Box::new(value as i64))
+    } else if type_id == TypeId::of::<u8>() {
+        Some(Box::new(value as u8))
+    } else if type_id == TypeId::of::<u16>() {
+        Some(Box::new(value as u16))
+    } else if type_id == TypeId::of::<u32>() {
+        Some(Box::new(value as u32))
+    } else if type_id == TypeId::of::<u64>() {
+        Some(Box::new(value as u64))
+    } else {
+        None
+    }
+}
+
+/// Utility that converts a reflected numeric primitive into a boxed value of the numeric primitive type with the
+/// given `TypeId`, checking that the value fits.
+///
+/// Supports the same types as `reflect_number_as_f64`. Unlike `reflect_number_from_f64`, conversions never saturate
+/// or truncate:
+/// - Integers convert to other integer types with `TryFrom`.
+/// - Integers only convert to float types if the float represents them exactly, e.g. not `u64::MAX` as an `f64`.
+/// - Floats only convert to integer types if they are whole numbers.
+/// - `f64` only converts to `f32` if it is within the range of `f32`, rounding to the nearest `f32`.
+///
+/// Returns `ReflectError::NumericOutOfRange` if the value does not fit, or `ReflectError::UnsupportedNumericType` if
+/// either type is not supported.
+pub fn reflect_number_coerce(
+    value: &dyn Reflect,
+    type_id: TypeId,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    if !is_reflect_number_type(type_id) {
+        return Err(ReflectError::UnsupportedNumericType);
+    }
+
+    let integer = match reflect_integer_as_i128(value) {
+        Some(integer) => integer,
+        None => {
+            let float = reflect_number_as_f64(value).ok_or(ReflectError::UnsupportedNumericType)?;
+            if type_id == TypeId::of::<f64>() || type_id == TypeId::of::<f32>() {
+                if type_id == TypeId::of::<f32>() && float.abs() > f64::from(f32::MAX) {
+                    return Err(ReflectError::NumericOutOfRange);
+                }
+                return reflect_number_from_f64(type_id, float)
+                    .ok_or(ReflectError::UnsupportedNumericType);
+            }
+            if !float.is_finite() || float.fract() != 0. {
+                return Err(ReflectError::NumericOutOfRange);
+            }
+            float as i128
+        }
+    };
+
+    macro_rules! try_from_integer {
+        ($($t:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$t>() {
+                    return <$t>::try_from(integer)
+                        .map(|value| Box::new(value) as Box<dyn Reflect>)
+                        .map_err(|_| ReflectError::NumericOutOfRange);
+                }
+            )*
+        };
+    }
+    try_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+    // The target is a float, which can only hold integers up to 2^24 (`f32`) or 2^53 (`f64`) exactly
+    let is_exact = if type_id == TypeId::of::<f32>() {
+        integer as f32 as i128 == integer
+    } else {
+        integer as f64 as i128 == integer
+    };
+    if !is_exact {
+        return Err(ReflectError::NumericOutOfRange);
+    }
+    reflect_number_from_f64(type_id, integer as f64).ok_or(ReflectError::UnsupportedNumericType)
+}
+
+/// Reads a reflected integer primitive as an `i128`, which can hold every supported integer type.
+fn reflect_integer_as_i128(value: &dyn Reflect) -> Option<i128> {
+    value
+        .downcast_ref::<i8>()
+        .map(|v| i128::from(*v))
+        .or_else(|| value.downcast_ref::<i16>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<i32>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<i64>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<u8>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<u16>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<u32>().map(|v| i128::from(*v)))
+        .or_else(|| value.downcast_ref::<u64>().map(|v| i128::from(*v)))
+}
+
+/// Returns whether the type is one of the numeric primitives supported by `reflect_number_as_f64`.
+pub fn is_reflect_number_type(type_id: TypeId) -> bool {
+    reflect_number_from_f64(type_id, 0.).is_some()
+}
+
+/// Reads a `RangeInclusive` custom attribute of any supported numeric type as a range of `f64`.
+///
+/// Ranges are added to fields with the `#[reflect(@0.0..=1.0_f32)]` attribute. Returns `None` if there is no range
+/// attribute.
+pub fn reflect_range_attribute(attributes: &CustomAttributes) -> Option<RangeInclusive<f64>> {
+    range_attribute_as_f64::<f64>(attributes)
+        .or_else(|| range_attribute_as_f64::<f32>(attributes))
+        .or_else(|| range_attribute_as_f64::<i8>(attributes))
+        .or_else(|| range_attribute_as_f64::<i16>(attributes))
+        .or_else(|| range_attribute_as_f64::<i32>(attributes))
+        .or_else(|| range_attribute_as_f64::<i64>(attributes))
+        .or_else(|| range_attribute_as_f64::<u8>(attributes))
+        .or_else(|| range_attribute_as_f64::<u16>(attributes))
+        .or_else(|| range_attribute_as_f64::<u32>(attributes))
+        .or_else(|| range_attribute_as_f64::<u64>(attributes))
+}
+
+fn range_attribute_as_f64<T: Reflect>(attributes: &CustomAttributes) -> Option<RangeInclusive<f64>>
+where
+    RangeInclusive<T>: Reflect,
+{
+    let range = attributes.get::<RangeInclusive<T>>()?;
+    Some(reflect_number_as_f64(range.start())?..=reflect_number_as_f64(range.end())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflect_number_as_f64_works() {
+        assert_eq!(reflect_number_as_f64(&-3_i8), Some(-3.));
+        assert_eq!(reflect_number_as_f64(&300_u16), Some(300.));
+        assert_eq!(reflect_number_as_f64(&-5_i32), Some(-5.));
+        assert_eq!(reflect_number_as_f64(&5_u64), Some(5.));
+        assert_eq!(reflect_number_as_f64(&0.5_f32), Some(0.5));
+        assert_eq!(reflect_number_as_f64(&0.25_f64), Some(0.25));
+        assert_eq!(reflect_number_as_f64(&true), None);
+        assert_eq!(reflect_number_as_f64(&"1".to_string()), None);
+    }
+
+    #[test]
+    fn reflect_number_from_f64_works() {
+        let value = reflect_number_from_f64(TypeId::of::<i32>(), -1.9).unwrap();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&-1));
+
+        let value = reflect_number_from_f64(TypeId::of::<u8>(), 300.).unwrap();
+        assert_eq!(value.downcast_ref::<u8>(), Some(&255));
+
+        let value = reflect_number_from_f64(TypeId::of::<f32>(), 0.5).unwrap();
+        assert_eq!(value.downcast_ref::<f32>(), Some(&0.5));
+
+        assert!(reflect_number_from_f64(TypeId::of::<bool>(), 1.).is_none());
+    }
+
+    #[test]
+    fn reflect_number_coerce_works() {
+        let value = reflect_number_coerce(&5_i32, TypeId::of::<i64>()).unwrap();
+        assert_eq!(value.downcast_ref::<i64>(), Some(&5));
+
+        let value = reflect_number_coerce(&-2.0_f64, TypeId::of::<i8>()).unwrap();
+        assert_eq!(value.downcast_ref::<i8>(), Some(&-2));
+
+        let value = reflect_number_coerce(&3_u8, TypeId::of::<f32>()).unwrap();
+        assert_eq!(value.downcast_ref::<f32>(), Some(&3.));
+
+        // Test values that do not fit the target type
+        assert_eq!(
+            reflect_number_coerce(&300_i64, TypeId::of::<i8>()).err(),
+            Some(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(
+            reflect_number_coerce(&-1_i32, TypeId::of::<u32>()).err(),
+            Some(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(
+            reflect_number_coerce(&1.5_f64, TypeId::of::<i32>()).err(),
+            Some(ReflectError::NumericOutOfRange)
+        );
+
+        // Test integers that a float can not represent exactly
+        assert_eq!(
+            reflect_number_coerce(&u64::MAX, TypeId::of::<f64>()).err(),
+            Some(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(
+            reflect_number_coerce(&16_777_217_i32, TypeId::of::<f32>()).err(),
+            Some(ReflectError::NumericOutOfRange)
+        );
+
+        // Test unsupported types
+        assert_eq!(
+            reflect_number_coerce(&true, TypeId::of::<i32>()).err(),
+            Some(ReflectError::UnsupportedNumericType)
+        );
+        assert_eq!(
+            reflect_number_coerce(&1_i32, TypeId::of::<bool>()).err(),
+            Some(ReflectError::UnsupportedNumericType)
+        );
+    }
+}
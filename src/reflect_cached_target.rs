@@ -0,0 +1,170 @@
+use bevy::{ecs::component::Tick, prelude::*};
+
+use crate::*;
+
+/// Wraps a [`ReflectTarget`], caching the last value read and only reading it again when the owning component or
+/// resource has changed.
+///
+/// Changes are detected using the change tick of the whole component or resource, not the field, so changing any other
+/// field also refreshes the value. Changes made within the same tick as the previous read are not detected. The cached
+/// value is also keyed by the resolved entity, so a `ReflectKind::ComponentByMarker` target that moves to another
+/// entity reads the value again.
+#[derive(Debug, Clone)]
+pub struct CachedReflectTarget<T> {
+    target: ReflectTarget,
+    cached: Option<(ResolvedReflectKind, Tick, T)>,
+    #[cfg(test)]
+    misses: usize,
+}
+
+impl<T: Reflect + Clone> CachedReflectTarget<T> {
+    /// Returns a cache for `target` that has not read a value yet.
+    pub fn new(target: ReflectTarget) -> Self {
+        Self {
+            target,
+            cached: None,
+            #[cfg(test)]
+            misses: 0,
+        }
+    }
+
+    /// Returns the target the value is read from.
+    pub fn target(&self) -> &ReflectTarget {
+        &self.target
+    }
+
+    /// Points the cache at a different target, removing the cached value so the next read reads the new target.
+    pub fn set_target(&mut self, target: ReflectTarget) {
+        self.target = target;
+        self.cached = None;
+    }
+
+    /// Returns the value of the field, re-using the cached value if the owner has not changed since it was read.
+    ///
+    /// The cache is cleared if the owner no longer exists.
+    pub fn read(&mut self, world: &World) -> Result<T, ReflectError> {
+        let (kind, change_tick) = match self.owner_change_tick(world) {
+            Ok(owner) => owner,
+            Err(err) => {
+                self.cached = None;
+                return Err(err);
+            }
+        };
+        if let Some((cached_kind, tick, value)) = &self.cached {
+            if *cached_kind == kind && *tick == change_tick {
+                return Ok(value.clone());
+            }
+        }
+
+        let value = self.target.with_field(world, |field| {
+            field
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or(ReflectError::InvalidDowncast)
+        })??;
+        #[cfg(test)]
+        {
+            self.misses += 1;
+        }
+        self.cached = Some((kind, change_tick, value.clone()));
+        Ok(value)
+    }
+
+    /// Returns the number of reads that had to read the value because it was not cached or had changed.
+    #[cfg(test)]
+    fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes the cached value, so the next read reads the value again.
+    pub fn clear(&mut self) {
+        self.cached = None;
+    }
+
+    /// Returns the resolved component or resource owning the field, and the tick it was last changed.
+    fn owner_change_tick(
+        &self,
+        world: &World,
+    ) -> Result<(ResolvedReflectKind, Tick), ReflectError> {
+        let kind = self.target.resolve_kind(world)?;
+        Ok((kind, last_changed_tick(world, kind)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ComponentA>();
+
+        world
+    }
+
+    #[test]
+    fn cached_read_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentA { value: 1 }).id();
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "value");
+        let mut cached = CachedReflectTarget::<i32>::new(target.clone());
+
+        // Test the first read reads the value
+        assert_eq!(cached.read(&world), Ok(1));
+        assert_eq!(cached.misses(), 1);
+
+        // Test the cached value is re-used across frames without changes
+        world.increment_change_tick();
+        assert_eq!(cached.read(&world), Ok(1));
+        world.increment_change_tick();
+        assert_eq!(cached.read(&world), Ok(1));
+        assert_eq!(cached.misses(), 1);
+
+        // Test the value is refreshed after a set
+        world.increment_change_tick();
+        target.set_value(&mut world, 2).unwrap();
+        assert_eq!(cached.read(&world), Ok(2));
+        assert_eq!(cached.misses(), 2);
+
+        // Test the error when the entity was despawned
+        world.despawn(entity);
+        assert_eq!(cached.read(&world), Err(ReflectError::EntityNotFound));
+    }
+
+    #[test]
+    fn cached_read_follows_target() {
+        let mut world = create_world();
+        let entity_a = world.spawn((ComponentA { value: 1 }, Marker)).id();
+        let entity_b = world.spawn(ComponentA { value: 2 }).id();
+
+        // Test setting a new target reads the new target, rather than returning the cached value
+        let target = ReflectTarget::new_component::<ComponentA>(entity_a, "value");
+        let mut cached = CachedReflectTarget::<i32>::new(target.clone());
+        assert_eq!(cached.read(&world), Ok(1));
+        cached.set_target(target.retarget_entity(entity_b));
+        assert_eq!(cached.target(), &target.retarget_entity(entity_b));
+        assert_eq!(cached.read(&world), Ok(2));
+
+        // Test a marker target that moves to another entity, changed in the same tick, reads the value again
+        let target = ReflectTarget::new_component_by_marker::<ComponentA, Marker>("value");
+        let mut cached = CachedReflectTarget::<i32>::new(target);
+        assert_eq!(cached.read(&world), Ok(1));
+        world.entity_mut(entity_a).remove::<Marker>();
+        world.entity_mut(entity_b).insert(Marker);
+        assert_eq!(cached.read(&world), Ok(2));
+        assert_eq!(cached.misses(), 2);
+    }
+}
@@ -1,7 +1,12 @@
 use std::any::TypeId;
 
-use bevy::{prelude::*, reflect::TypeRegistry};
+use bevy::{
+    ecs::{component::Tick, entity::EntityHashMap, world::FilteredEntityRef},
+    prelude::*,
+    reflect::TypeRegistry,
+};
 
+use crate::macros::reflect_span;
 use crate::*;
 
 /// Read the value of a field from an entity's component cast as the specified type.
@@ -9,8 +14,9 @@ pub fn reflect_component_read_path<T: Reflect + Clone>(
     entity_ref: &EntityRef,
     type_registry: &TypeRegistry,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<T, ReflectError> {
+    let path = path.as_ref();
     with_component_reflect_field(
         entity_ref,
         type_registry,
@@ -25,13 +31,33 @@ pub fn reflect_component_read_path<T: Reflect + Clone>(
     )?
 }
 
+/// Read the value of a field from an entity's component, converted to the specified type with `FromReflect`.
+///
+/// Unlike `reflect_component_read_path`, the type does not need to implement `Clone`.
+pub fn reflect_component_read_path_from_reflect<T: FromReflect>(
+    entity_ref: &EntityRef,
+    type_registry: &TypeRegistry,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<T, ReflectError> {
+    let path = path.as_ref();
+    with_component_reflect_field(
+        entity_ref,
+        type_registry,
+        component_type_id,
+        path,
+        |field| T::from_reflect(field).ok_or(ReflectError::InvalidDowncast),
+    )?
+}
+
 /// Utility that reads the value of a field on a resource by path, downcast to the provided type.
 pub fn reflect_component_read_path_serialized(
     world: &World,
     entity: Entity,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<String, ReflectError> {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
@@ -48,14 +74,56 @@ pub fn reflect_component_read_path_serialized(
     )?
 }
 
+/// Reads the serialized value of a field on every entity that has the component, sorted by entity.
+///
+/// The type registry is only locked once for all entities. Each entity has its own result, so a path that fails on
+/// one entity does not prevent reading the others. Returns an empty `Vec` if the component has never been added to
+/// the world.
+pub fn reflect_component_read_path_serialized_all(
+    world: &World,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Vec<(Entity, Result<String, ReflectError>)> {
+    let path = path.as_ref();
+    let Some(component_id) = world.components().get_id(component_type_id) else {
+        return Vec::new();
+    };
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut entities: Vec<Entity> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(component_id))
+        .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+        .collect();
+    entities.sort();
+
+    entities
+        .into_iter()
+        .map(|entity| {
+            let result = with_component_reflect_field(
+                &world.entity(entity),
+                &type_registry,
+                component_type_id,
+                path,
+                |field| serialize_reflect_value(&type_registry, field),
+            )
+            .and_then(|result| result);
+            (entity, result)
+        })
+        .collect()
+}
+
 /// Utility that returns the value of `reflect_partial_eq` against a serialized value on a component.
 pub fn reflect_component_partial_eq_serialized(
     world: &mut World,
     entity: Entity,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     serialized_value: &str,
 ) -> Result<bool, ReflectError> {
+    let path = path.as_ref();
     // De-serialize the value into a `Box<dyn Reflect>`
     let value = deserialize_reflect_value(world, serialized_value)?;
 
@@ -83,29 +151,39 @@ pub fn reflect_component_set_path_serialized(
     world: &mut World,
     entity: Entity,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
+    serialized_value: &str,
+) -> ReflectSetResult {
+    let path = path.as_ref();
+    reflect_component_set_path_serialized_with_format(
+        world,
+        entity,
+        component_type_id,
+        path,
+        serialized_value,
+        SerializationFormat::Ron,
+    )
+}
+
+/// Utility that sets the value of a field on a component by path, from a value serialized in the specified format.
+pub fn reflect_component_set_path_serialized_with_format(
+    world: &mut World,
+    entity: Entity,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
     serialized_value: &str,
+    format: SerializationFormat,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
     // De-serialize the value into a `Box<dyn Reflect>`
-    let value = deserialize_reflect_value(world, serialized_value)?;
+    let value = deserialize_reflect_value_with_format(world, serialized_value, format)?;
 
     with_reflect_component_field_mut_world(
         world,
         component_type_id,
         entity,
         path,
-        |reflect_field| {
-            let is_eq = reflect_field.reflect_partial_eq(value.as_reflect());
-            match is_eq {
-                Some(true) => Ok(ReflectSetSuccess::NoChanges),
-                _ => match reflect_field.set(value) {
-                    Ok(_) => Ok(ReflectSetSuccess::Changed),
-                    // NOTE: The error message contained below is not useful, it is usually the name of the dynamic type,
-                    // e.g. "DynamicStruct".
-                    Err(_) => Err(ReflectError::SetValueFailed),
-                },
-            }
-        },
+        |reflect_field| reflect_set_field(reflect_field, value),
     )?
 }
 
@@ -116,8 +194,9 @@ pub fn reflect_component_read_path_from_world<T: Reflect + Clone>(
     world: &World,
     entity: Entity,
     component_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<T, ReflectError> {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
@@ -128,35 +207,124 @@ pub fn reflect_component_read_path_from_world<T: Reflect + Clone>(
     reflect_component_read_path(&entity_ref, &type_registry, component_type_id, path)
 }
 
+/// Same as `reflect_component_read_path_from_world`, but also returns the tick the component was last changed.
+///
+/// NOTE: Change ticks are tracked per component, not per field, so the tick advances when any field of the component
+/// is changed.
+pub fn reflect_component_read_path_with_tick<T: Reflect + Clone>(
+    world: &World,
+    entity: Entity,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<(T, Tick), ReflectError> {
+    let value = reflect_component_read_path_from_world(world, entity, component_type_id, path)?;
+    let tick = last_changed_tick(
+        world,
+        ResolvedReflectKind::Component(entity, component_type_id),
+    )?;
+    Ok((value, tick))
+}
+
+/// Returns the `TypeId` of the field at a path on an entity's component, e.g. to look up type data for the field's
+/// type.
+pub fn reflect_component_field_type_id(
+    world: &World,
+    entity: Entity,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<TypeId, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(ReflectError::EntityNotFound)?;
+
+    with_component_reflect_field(
+        &entity_ref,
+        &type_registry,
+        component_type_id,
+        path,
+        |field| {
+            field
+                .get_represented_type_info()
+                .map(|type_info| type_info.type_id())
+                .ok_or(ReflectError::TypeRegistrationNotFound)
+        },
+    )?
+}
+
+/// Set the value of a field by its path on a component on an entity to a new value computed from the current one.
+///
+/// Returns `ReflectError::InvalidDowncast` if the field is not a `T`.
+pub fn reflect_component_update_path<T: Reflect + Clone>(
+    world: &mut World,
+    component_type_id: TypeId,
+    entity: Entity,
+    path: impl AsRef<str>,
+    update_fn: impl FnOnce(T) -> T,
+) -> ReflectSetResult {
+    with_reflect_component_field_mut_world(
+        world,
+        component_type_id,
+        entity,
+        path,
+        |reflect_field| {
+            let value = reflect_field
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or(ReflectError::InvalidDowncast)?;
+            reflect_set_field(reflect_field, Box::new(update_fn(value)))
+        },
+    )?
+}
+
 /// Set the value of a field by its path on a component on an entity.
 pub fn reflect_component_set_path<T: Reflect>(
     world: &mut World,
     component_type_id: TypeId,
     entity: Entity,
-    path: &str,
+    path: impl AsRef<str>,
     value: T,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
     with_reflect_component_field_mut_world(
         world,
         component_type_id,
         entity,
         path,
-        |reflect_field| {
-            let value: Box<dyn Reflect> = Box::new(value);
-            let is_eq = reflect_field.reflect_partial_eq(value.as_reflect());
-            match is_eq {
-                Some(true) => Ok(ReflectSetSuccess::NoChanges),
-                _ => match reflect_field.set(value) {
-                    Ok(_) => Ok(ReflectSetSuccess::Changed),
-                    // NOTE: The error message contained below is not useful, it is usually the name of the dynamic type,
-                    // e.g. "DynamicStruct".
-                    Err(_) => Err(ReflectError::SetValueFailed),
-                },
-            }
-        },
+        |reflect_field| reflect_set_field(reflect_field, Box::new(value)),
     )?
 }
 
+/// Set the value of a field by its path on a component on an entity, inserting the component's default value first if
+/// the entity does not have it.
+///
+/// Returns `ReflectError::NoDefaultValue` if the component needs inserting but does not reflect `Default`.
+pub fn reflect_component_set_path_or_insert_default<T: Reflect>(
+    world: &mut World,
+    component_type_id: TypeId,
+    entity: Entity,
+    path: impl AsRef<str>,
+    value: T,
+) -> ReflectSetResult {
+    let has_component = world
+        .get_entity(entity)
+        .ok_or(ReflectError::EntityNotFound)?
+        .contains_type_id(component_type_id);
+    if !has_component {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let reflect_component = get_reflect_component(&type_registry, component_type_id)?;
+        let default_value = reflect_default_value(&type_registry, component_type_id)?;
+        reflect_component.insert(
+            &mut world.entity_mut(entity),
+            default_value.as_reflect(),
+            &type_registry,
+        );
+    }
+
+    reflect_component_set_path(world, component_type_id, entity, path, value)
+}
+
 /// Apply the value of a field by its path on a component on an entity.
 ///
 /// See `Reflect::apply` docs for more information.
@@ -164,15 +332,36 @@ pub fn reflect_component_apply_path(
     world: &mut World,
     component_type_id: TypeId,
     entity: Entity,
-    path: &str,
+    path: impl AsRef<str>,
     value: &dyn Reflect,
 ) -> Result<(), ReflectError> {
+    let path = path.as_ref();
     with_reflect_component_field_mut_world(world, component_type_id, entity, path, |field| {
         field.apply(value);
         Ok(())
     })?
 }
 
+/// Apply a serialized value to a field by its path on a component on an entity.
+///
+/// Unlike `reflect_component_set_path_serialized`, the value may be a partial patch. For example, a struct value only
+/// containing some of its fields will only update those fields.
+///
+/// Panics if the de-serialized value can not be applied to the field. See `Reflect::apply` docs for more information.
+pub fn reflect_component_apply_path_serialized(
+    world: &mut World,
+    entity: Entity,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+    serialized_value: &str,
+) -> Result<(), ReflectError> {
+    let path = path.as_ref();
+    // De-serialize the value into a `Box<dyn Reflect>`
+    let value = deserialize_reflect_value(world, serialized_value)?;
+
+    reflect_component_apply_path(world, component_type_id, entity, path, value.as_reflect())
+}
+
 /// Utility that copies the properties of components from one entity to another. Only components that both entities
 /// have in common are copied.
 ///
@@ -200,13 +389,36 @@ pub fn reflect_copy_shared_component_props(
     target_entity: Entity,
     source_entity: Entity,
     type_id_filter: &impl Fn(TypeId) -> bool,
+) -> Result<(), ReflectError> {
+    reflect_copy_shared_component_props_with_entity_map(
+        world,
+        target_entity,
+        source_entity,
+        type_id_filter,
+        None,
+    )
+}
+
+/// Same as `reflect_copy_shared_component_props`, but remaps any `Entity` fields on the copied components using
+/// `entity_map`.
+///
+/// Without an entity map, `Entity` fields are copied verbatim and keep pointing at the same entities as the source
+/// components.
+pub fn reflect_copy_shared_component_props_with_entity_map(
+    world: &mut World,
+    target_entity: Entity,
+    source_entity: Entity,
+    type_id_filter: &impl Fn(TypeId) -> bool,
+    entity_map: Option<&EntityHashMap<Entity>>,
 ) -> Result<(), ReflectError> {
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    // Collect a vector of TypeIds for components that both entities have in common, ignoring anywhere
-    // `type_id_filter` returns False.
-    let component_type_ids: Vec<TypeId> = {
+    // Clone the values of the components that both entities have in common, ignoring anywhere `type_id_filter`
+    // returns False.
+    // NOTE: Every value is captured before the target is mutated, so a missing entity or component returns an error
+    // without leaving the copy partially applied.
+    let new_values: Vec<(&ReflectComponent, Box<dyn Reflect>)> = {
         let source_entity_ref = world
             .get_entity(source_entity)
             .ok_or(ReflectError::EntityNotFound)?;
@@ -228,43 +440,76 @@ pub fn reflect_copy_shared_component_props(
             .filter(|type_id| type_registry.get(*type_id).is_some())
             // Check against type_id_filter
             .filter(|type_id| type_id_filter(*type_id))
-            .collect()
+            .map(|type_id| -> Result<_, ReflectError> {
+                let reflect_component = get_reflect_component(&type_registry, type_id)?;
+                let mut new_value = reflect_component
+                    .reflect(source_entity_ref)
+                    .ok_or(ReflectError::EntityDoesNotHaveComponent)?
+                    .clone_value();
+                if let Some(entity_map) = entity_map {
+                    reflect_map_entities(new_value.as_reflect_mut(), entity_map);
+                }
+                Ok((reflect_component, new_value))
+            })
+            .collect::<Result<_, _>>()?
     };
 
-    // Copy components from the source component to the target component, if the target entity contains that component
-    for type_id in component_type_ids.iter() {
-        let registration = type_registry
-            .get(*type_id)
-            .ok_or(ReflectError::TypeRegistrationNotFound)?;
-        let reflect_component = registration
-            .data::<ReflectComponent>()
-            .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
+    // Apply the cloned values to the target entity
+    let mut target_entity_mut = world
+        .get_entity_mut(target_entity)
+        .ok_or(ReflectError::EntityNotFound)?;
+    for (reflect_component, new_value) in new_values.iter() {
+        if let Some(mut reflect_target) = reflect_component.reflect_mut(&mut target_entity_mut) {
+            reflect_target.apply(new_value.as_reflect());
+        }
+    }
 
-        // Clone the value from of the source component
+    Ok(())
+}
+
+/// Spawns a new entity with a copy of every reflectable component on the source entity, returning the new entity.
+///
+/// Components are copied if their type is registered with `ReflectComponent` type data, ignoring anywhere
+/// `type_id_filter` returns `false`. Hierarchy components such as `Parent` and `Children` are copied verbatim, so
+/// filter them out if the clone should not join the source's hierarchy.
+pub fn reflect_clone_entity(
+    world: &mut World,
+    source_entity: Entity,
+    type_id_filter: &impl Fn(TypeId) -> bool,
+) -> Result<Entity, ReflectError> {
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    // Clone the values of the source components first, because we need mutable world access to spawn the new entity
+    let components: Vec<(&ReflectComponent, Box<dyn Reflect>)> = {
         let source_entity_ref = world
             .get_entity(source_entity)
             .ok_or(ReflectError::EntityNotFound)?;
-        let reflect_source = reflect_component
-            .reflect(source_entity_ref)
-            .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
-        let new_value = reflect_source.clone_value();
-
-        // // TODO: Remove debug logging when done
-        // error!("COPY COMPONENT: {}", registration.type_info().type_path());
-        // if let bevy::reflect::ReflectRef::Struct(data) = new_value.reflect_ref() {
-        //     error!("  {:?}", data.as_reflect());
-        // };
-
-        // Apply the cloned value to the target entity, if it has the component
-        let mut target_entity_ref = world
-            .get_entity_mut(target_entity)
-            .ok_or(ReflectError::EntityNotFound)?;
-        if let Some(mut reflect_target) = reflect_component.reflect_mut(&mut target_entity_ref) {
-            reflect_target.apply(new_value.as_reflect());
-        }
+        source_entity_ref
+            .archetype()
+            .components()
+            .filter_map(|component_id| {
+                world
+                    .components()
+                    .get_info(component_id)
+                    .and_then(|component_info| component_info.type_id())
+            })
+            .filter(|type_id| type_id_filter(*type_id))
+            .filter_map(|type_id| type_registry.get_type_data::<ReflectComponent>(type_id))
+            .filter_map(|reflect_component| {
+                reflect_component
+                    .reflect(source_entity_ref)
+                    .map(|value| (reflect_component, value.clone_value()))
+            })
+            .collect()
+    };
+
+    let mut entity_mut = world.spawn_empty();
+    for (reflect_component, value) in components.iter() {
+        reflect_component.insert(&mut entity_mut, value.as_reflect(), &type_registry);
     }
 
-    Ok(())
+    Ok(entity_mut.id())
 }
 
 // NOTE: Keep this around as a reference
@@ -288,6 +533,27 @@ pub fn reflect_copy_shared_component_props(
 //         .cloned()
 // }
 
+/// Runs a closure with readonly access to a reflected component on an entity.
+///
+/// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
+pub fn with_component_reflect<T>(
+    world: &World,
+    entity: Entity,
+    component_type_id: TypeId,
+    read_fn: impl FnOnce(&dyn Reflect) -> T,
+) -> Result<T, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(ReflectError::EntityNotFound)?;
+    let reflect_component = get_reflect_component(&type_registry, component_type_id)?;
+    reflect_component
+        .reflect(entity_ref)
+        .ok_or(ReflectError::EntityDoesNotHaveComponent)
+        .map(read_fn)
+}
+
 /// Runs a closure with the readonly reflected value of a path on an entity's component.
 ///
 /// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
@@ -316,21 +582,44 @@ pub fn with_component_reflect_field<T>(
     entity_ref: &EntityRef,
     type_registry: &TypeRegistry,
     component_type_id: TypeId,
-    field_path: &str,
+    field_path: impl AsRef<str>,
     read_fn: impl FnOnce(&dyn Reflect) -> T,
 ) -> Result<T, ReflectError> {
-    let registration = type_registry
-        .get(component_type_id)
-        .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_component = registration
-        .data::<ReflectComponent>()
-        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
+    let field_path = field_path.as_ref();
+    let reflect_component = get_reflect_component(&type_registry, component_type_id)?;
     let dyn_reflect = reflect_component
         .reflect(*entity_ref)
         .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
     dyn_reflect
         .reflect_path(field_path)
-        .map_err(|err| ReflectError::ReflectPath(err.to_string()))
+        .map_err(|err| reflect_path_error(dyn_reflect, field_path, err))
+        .map(read_fn)
+}
+
+/// Runs a closure with the readonly reflected value of a path on a component of a `FilteredEntityRef`, e.g. from a
+/// query built with `QueryBuilder`.
+///
+/// Returns `ReflectError::NoAccess` if the entity has the component but `entity_ref` does not have read access to it,
+/// and `ReflectError::EntityDoesNotHaveComponent` if the entity does not have the component.
+pub fn with_filtered_component_reflect_field<T>(
+    entity_ref: &FilteredEntityRef,
+    type_registry: &TypeRegistry,
+    component_type_id: TypeId,
+    field_path: impl AsRef<str>,
+    read_fn: impl FnOnce(&dyn Reflect) -> T,
+) -> Result<T, ReflectError> {
+    let field_path = field_path.as_ref();
+    let reflect_component = get_reflect_component(&type_registry, component_type_id)?;
+    let Some(dyn_reflect) = reflect_component.reflect(entity_ref.clone()) else {
+        return Err(if entity_ref.contains_type_id(component_type_id) {
+            ReflectError::NoAccess
+        } else {
+            ReflectError::EntityDoesNotHaveComponent
+        });
+    };
+    dyn_reflect
+        .reflect_path(field_path)
+        .map_err(|err| reflect_path_error(dyn_reflect, field_path, err))
         .map(read_fn)
 }
 
@@ -360,34 +649,78 @@ pub fn with_reflect_component_field_mut_world<T>(
     world: &mut World,
     component_type_id: TypeId,
     entity: Entity,
-    path: &str,
+    path: impl AsRef<str>,
     update_fn: impl FnOnce(&mut dyn Reflect) -> T,
 ) -> Result<T, ReflectError> {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
+    reflect_span!(
+        "with_reflect_component_field_mut_world",
+        type_path = type_registry
+            .get_type_info(component_type_id)
+            .map_or("", |info| info.type_path()),
+        path,
+        ?entity
+    );
 
-    let registration = type_registry
-        .get(component_type_id)
-        .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_component = registration
-        .data::<ReflectComponent>()
-        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
     let mut entity_mut = world
         .get_entity_mut(entity)
         .ok_or(ReflectError::EntityNotFound)?;
+
+    with_component_reflect_field_mut(
+        &mut entity_mut,
+        &type_registry,
+        component_type_id,
+        path,
+        update_fn,
+    )
+}
+
+/// Runs a closure with mutable access to the reflected value of a path on an entity's component.
+///
+/// Unlike `with_reflect_component_field_mut_world`, this only requires mutable access to the entity.
+///
+/// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
+///
+/// ```ignore
+/// let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+/// let type_registry = app_type_registry.read();
+/// let mut entity_mut = world.entity_mut(entity);
+/// let result: Result<bool, ReflectError> = with_component_reflect_field_mut(
+///     &mut entity_mut,
+///     &type_registry,
+///     TypeId::of::<MyComponent>(),
+///     "value",
+///     |field| field.set(Box::new(2)).is_ok(),
+/// );
+/// ```
+pub fn with_component_reflect_field_mut<T>(
+    entity_mut: &mut EntityWorldMut,
+    type_registry: &TypeRegistry,
+    component_type_id: TypeId,
+    field_path: impl AsRef<str>,
+    update_fn: impl FnOnce(&mut dyn Reflect) -> T,
+) -> Result<T, ReflectError> {
+    let field_path = field_path.as_ref();
+    let reflect_component = get_reflect_component(&type_registry, component_type_id)?;
     let mut dyn_reflect = reflect_component
-        .reflect_mut(&mut entity_mut)
+        .reflect_mut(entity_mut)
         .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
 
-    match dyn_reflect.reflect_path_mut(path) {
+    match dyn_reflect.reflect_path_mut(field_path) {
         Ok(reflect_field) => Ok(update_fn(reflect_field)),
-        Err(err) => Err(ReflectError::ReflectPath(err.to_string())),
+        Err(err) => Err(reflect_path_error(
+            dyn_reflect.as_reflect(),
+            field_path,
+            err,
+        )),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use bevy::ecs::system::SystemState;
+    use bevy::ecs::{query::QueryBuilder, system::SystemState};
 
     use super::*;
 
@@ -415,6 +748,50 @@ mod tests {
     #[reflect(Component)]
     struct ComponentC(u32);
 
+    #[derive(Reflect, Default, PartialEq, Eq, Debug)]
+    struct InnerStruct {
+        a: u32,
+        b: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentD {
+        inner: InnerStruct,
+    }
+
+    // NOTE: Does not implement `Clone`, so it can only be read with `FromReflect`
+    #[derive(Reflect, Default, PartialEq, Debug)]
+    struct NoCloneStruct {
+        a: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentF {
+        inner: NoCloneStruct,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct GenericComponent<A, B> {
+        first: A,
+        second: B,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct ComponentE {
+        target: Entity,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct ComponentG {
+        value: u32,
+        other: u32,
+    }
+
     #[derive(Component)]
     struct NonReflectComponent;
 
@@ -427,6 +804,14 @@ mod tests {
         type_registry.write().register::<ComponentA>();
         type_registry.write().register::<ComponentB>();
         type_registry.write().register::<ComponentC>();
+        type_registry.write().register::<ComponentD>();
+        type_registry.write().register::<InnerStruct>();
+        type_registry.write().register::<ComponentE>();
+        type_registry.write().register::<ComponentF>();
+        type_registry.write().register::<ComponentG>();
+        type_registry
+            .write()
+            .register::<GenericComponent<u32, InnerStruct>>();
 
         world
     }
@@ -482,6 +867,186 @@ mod tests {
         assert_eq!(component.value2, EnumA::B(2));
     }
 
+    #[test]
+    fn reflect_component_field_type_id_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentD::default()).id();
+
+        assert_eq!(
+            reflect_component_field_type_id(&world, entity, TypeId::of::<ComponentD>(), "inner"),
+            Ok(TypeId::of::<InnerStruct>())
+        );
+        assert_eq!(
+            reflect_component_field_type_id(&world, entity, TypeId::of::<ComponentD>(), "inner.a"),
+            Ok(TypeId::of::<u32>())
+        );
+        assert_eq!(
+            reflect_component_field_type_id(&world, entity, TypeId::of::<ComponentA>(), "value1"),
+            Err(ReflectError::EntityDoesNotHaveComponent)
+        );
+    }
+
+    #[test]
+    fn generic_component_type_path_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn(GenericComponent {
+                first: 3_u32,
+                second: InnerStruct { a: 1, b: 2 },
+            })
+            .id();
+        let type_id = TypeId::of::<GenericComponent<u32, InnerStruct>>();
+
+        // Test the generic type path resolves, with or without whitespace between the type parameters
+        let type_path = GenericComponent::<u32, InnerStruct>::type_path();
+        assert_eq!(
+            get_type_id_for_type_path_from_world(&world, type_path),
+            Some(type_id)
+        );
+        assert_eq!(
+            get_type_id_for_type_path_from_world(
+                &world,
+                "bevy_reflect_utils::reflect_component::tests::GenericComponent<u32,bevy_reflect_utils::reflect_component::tests::InnerStruct>"
+            ),
+            Some(type_id)
+        );
+        assert_eq!(
+            get_type_id_for_type_path_from_world(
+                &world,
+                "bevy_reflect_utils::reflect_component::tests::GenericComponent<u32, u32>"
+            ),
+            None
+        );
+
+        // Test fields can be read through the resolved type id
+        assert_eq!(
+            reflect_component_read_path_from_world::<u32>(&world, entity, type_id, "second.b"),
+            Ok(2)
+        );
+        assert_eq!(
+            reflect_component_read_path_from_world::<u32>(&world, entity, type_id, "first"),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn reflect_set_path_or_insert_default_works() {
+        let mut world = create_world();
+        let entity = world.spawn_empty().id();
+
+        // Test the component is inserted with its default value before the field is set
+        reflect_component_set_path_or_insert_default(
+            &mut world,
+            TypeId::of::<ComponentG>(),
+            entity,
+            "value",
+            3_u32,
+        )
+        .unwrap();
+        let component = world.get::<ComponentG>(entity).unwrap();
+        assert_eq!((component.value, component.other), (3, 0));
+
+        // Test an existing component is updated without being reset
+        world.get_mut::<ComponentG>(entity).unwrap().other = 7;
+        reflect_component_set_path_or_insert_default(
+            &mut world,
+            TypeId::of::<ComponentG>(),
+            entity,
+            "value",
+            4_u32,
+        )
+        .unwrap();
+        let component = world.get::<ComponentG>(entity).unwrap();
+        assert_eq!((component.value, component.other), (4, 7));
+
+        // Test the error when the component does not reflect `Default`
+        assert_eq!(
+            reflect_component_set_path_or_insert_default(
+                &mut world,
+                TypeId::of::<ComponentC>(),
+                entity,
+                "0",
+                1_u32,
+            ),
+            Err(ReflectError::NoDefaultValue)
+        );
+    }
+
+    #[test]
+    fn with_filtered_component_reflect_field_works() {
+        let mut world = create_world();
+        world.spawn(ComponentC(3));
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = app_type_registry.read();
+
+        // Test reading through a query with access to the component
+        let component_id = world.component_id::<ComponentC>().unwrap();
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(component_id)
+            .build();
+        let entity_ref = query.single(&world);
+        let result = with_filtered_component_reflect_field(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentC>(),
+            "0",
+            |field| field.downcast_ref::<u32>().cloned(),
+        );
+        assert_eq!(result, Ok(Some(3)));
+
+        // Test `NoAccess` is returned when the query matches the component without reading it
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .with::<ComponentC>()
+            .build();
+        let entity_ref = query.single(&world);
+        let result = with_filtered_component_reflect_field(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentC>(),
+            "0",
+            |_| (),
+        );
+        assert_eq!(result, Err(ReflectError::NoAccess));
+
+        // Test the error when the entity does not have the component
+        let result = with_filtered_component_reflect_field(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentA>(),
+            "value1",
+            |_| (),
+        );
+        assert_eq!(result, Err(ReflectError::EntityDoesNotHaveComponent));
+    }
+
+    #[test]
+    fn reflect_component_read_path_serialized_all_works() {
+        let mut world = create_world();
+        let entity_a = world.spawn(ComponentC(1)).id();
+        let entity_b = world.spawn((ComponentC(2), ComponentB)).id();
+        world.spawn(ComponentB);
+        let entity_c = world.spawn(ComponentC(3)).id();
+
+        let results =
+            reflect_component_read_path_serialized_all(&world, TypeId::of::<ComponentC>(), "0");
+        assert_eq!(
+            results,
+            vec![
+                (entity_a, Ok("{\"u32\":1}".to_string())),
+                (entity_b, Ok("{\"u32\":2}".to_string())),
+                (entity_c, Ok("{\"u32\":3}".to_string())),
+            ]
+        );
+
+        // Test a component that was never added returns no results
+        assert!(reflect_component_read_path_serialized_all(
+            &world,
+            TypeId::of::<ComponentD>(),
+            "inner"
+        )
+        .is_empty());
+    }
+
     #[test]
     fn reflect_set_path_errors() {
         let mut world = create_world();
@@ -505,6 +1070,93 @@ mod tests {
         assert!(matches!(result, Err(ReflectError::ReflectPath(_))));
     }
 
+    #[test]
+    fn reflect_component_apply_path_serialized_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn(ComponentD {
+                inner: InnerStruct { a: 1, b: 2 },
+            })
+            .id();
+
+        // Apply a patch that only contains `InnerStruct::a`
+        let result = reflect_component_apply_path_serialized(
+            &mut world,
+            entity,
+            TypeId::of::<ComponentD>(),
+            "inner",
+            "{\"bevy_reflect_utils::reflect_component::tests::InnerStruct\":(a:5)}",
+        );
+        assert_eq!(result, Ok(()));
+
+        // Test only `InnerStruct::a` was changed
+        let component = world.entity(entity).get::<ComponentD>().unwrap();
+        assert_eq!(component.inner, InnerStruct { a: 5, b: 2 });
+    }
+
+    #[test]
+    fn with_component_reflect_field_mut_works() {
+        let mut world = create_world();
+        let entity = run_with_commands(&mut world, |commands| {
+            commands
+                .spawn(ComponentA {
+                    value1: EnumA::A,
+                    value2: EnumA::A,
+                })
+                .id()
+        });
+
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = app_type_registry.read();
+        let mut entity_mut = world.entity_mut(entity);
+
+        // Set the value of `ComponentA::value1` via the `EntityWorldMut`
+        let result = with_component_reflect_field_mut(
+            &mut entity_mut,
+            &type_registry,
+            TypeId::of::<ComponentA>(),
+            "value1",
+            |field| field.set(Box::new(EnumA::B(3))).is_ok(),
+        );
+        assert_eq!(result, Ok(true));
+
+        // Test the value was set correctly on the component
+        let component = world.entity(entity).get::<ComponentA>().unwrap();
+        assert_eq!(component.value1, EnumA::B(3));
+        assert_eq!(component.value2, EnumA::A);
+    }
+
+    #[test]
+    fn reflect_component_read_path_from_reflect_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn(ComponentF {
+                inner: NoCloneStruct { a: 3 },
+            })
+            .id();
+
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let entity_ref = world.entity(entity);
+
+        // Test reading a type that does not implement `Clone`
+        let value = reflect_component_read_path_from_reflect::<NoCloneStruct>(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentF>(),
+            "inner",
+        );
+        assert_eq!(value, Ok(NoCloneStruct { a: 3 }));
+
+        // Test the error when the field is a different type
+        let value = reflect_component_read_path_from_reflect::<NoCloneStruct>(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentF>(),
+            "inner.a",
+        );
+        assert_eq!(value, Err(ReflectError::InvalidDowncast));
+    }
+
     #[test]
     fn reflect_read_path_works() {
         let mut world = create_world();
@@ -613,6 +1265,49 @@ mod tests {
             "value2.0",
         );
         assert!(matches!(result, Err(ReflectError::InvalidDowncast)));
+
+        // Test we get a descriptive error when a tuple index is out of range
+        let result = reflect_component_read_path::<u32>(
+            &entity_ref,
+            &type_registry,
+            TypeId::of::<ComponentA>(),
+            "value2.3",
+        );
+        assert_eq!(
+            result,
+            Err(ReflectError::ReflectPath(
+                "index 3 out of range for tuple of arity 1".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn reflect_component_read_path_with_tick_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentC(1)).id();
+
+        let (value, tick) = reflect_component_read_path_with_tick::<u32>(
+            &world,
+            entity,
+            TypeId::of::<ComponentC>(),
+            "0",
+        )
+        .unwrap();
+        assert_eq!(value, 1);
+
+        // Test the tick advances after setting the value in a later tick
+        world.increment_change_tick();
+        reflect_component_set_path(&mut world, TypeId::of::<ComponentC>(), entity, "0", 2u32)
+            .unwrap();
+        let (value, new_tick) = reflect_component_read_path_with_tick::<u32>(
+            &world,
+            entity,
+            TypeId::of::<ComponentC>(),
+            "0",
+        )
+        .unwrap();
+        assert_eq!(value, 2);
+        assert!(new_tick.is_newer_than(tick, world.read_change_tick()));
     }
 
     #[test]
@@ -709,6 +1404,36 @@ mod tests {
         assert_eq!(target_component.0, 2);
     }
 
+    #[test]
+    fn reflect_copy_shared_component_props_leaves_target_unchanged_on_error() {
+        // NOTE: Registered, but without `#[reflect(Component)]`, so copying it fails
+        #[derive(Component, Reflect, Default)]
+        struct UnreflectedComponent(u32);
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<UnreflectedComponent>();
+
+        // NOTE: `ComponentC` is registered with the world first, so it comes before `UnreflectedComponent` and would be
+        // applied before the error if the values were not all captured first
+        let source_entity = world.spawn((ComponentC(1), UnreflectedComponent(1))).id();
+        let target_entity = world.spawn((ComponentC(2), UnreflectedComponent(2))).id();
+
+        assert_eq!(
+            reflect_copy_shared_component_props(&mut world, target_entity, source_entity, &|_| {
+                true
+            }),
+            Err(ReflectError::TypeRegistrationInvalidCast)
+        );
+
+        // Test the target entity was left unchanged
+        let target = world.entity(target_entity);
+        assert_eq!(target.get::<ComponentC>().unwrap().0, 2);
+        assert_eq!(target.get::<UnreflectedComponent>().unwrap().0, 2);
+    }
+
     #[test]
     fn reflect_copy_shared_component_props_works_when_target_does_no_have_entity() {
         let mut world = create_world();
@@ -735,6 +1460,112 @@ mod tests {
         assert!(!world.entity(target_entity).contains::<ComponentA>());
     }
 
+    #[test]
+    fn with_component_reflect_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn(ComponentD {
+                inner: InnerStruct { a: 1, b: 2 },
+            })
+            .id();
+
+        // Test reading two fields in one closure call
+        let result =
+            with_component_reflect(&world, entity, TypeId::of::<ComponentD>(), |component| {
+                let a = component
+                    .reflect_path("inner.a")
+                    .ok()?
+                    .downcast_ref::<u32>()?;
+                let b = component
+                    .reflect_path("inner.b")
+                    .ok()?
+                    .downcast_ref::<u32>()?;
+                Some(a + b)
+            });
+        assert_eq!(result, Ok(Some(3)));
+
+        // Test the error when the entity does not have the component
+        let result = with_component_reflect(&world, entity, TypeId::of::<ComponentA>(), |_| ());
+        assert_eq!(result, Err(ReflectError::EntityDoesNotHaveComponent));
+    }
+
+    #[test]
+    fn reflect_copy_shared_component_props_remaps_entities() {
+        let mut world = create_world();
+        let source_target = world.spawn_empty().id();
+        let target_target = world.spawn_empty().id();
+        let source_entity = world
+            .spawn(ComponentE {
+                target: source_target,
+            })
+            .id();
+        let target_entity = world
+            .spawn(ComponentE {
+                target: Entity::PLACEHOLDER,
+            })
+            .id();
+
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(source_target, target_target);
+
+        let result = reflect_copy_shared_component_props_with_entity_map(
+            &mut world,
+            target_entity,
+            source_entity,
+            &|_| true,
+            Some(&entity_map),
+        );
+        assert_eq!(result, Ok(()));
+
+        // Test the `Entity` field was remapped on the target, and the source is unchanged
+        assert_eq!(
+            world.get::<ComponentE>(target_entity).unwrap().target,
+            target_target
+        );
+        assert_eq!(
+            world.get::<ComponentE>(source_entity).unwrap().target,
+            source_target
+        );
+    }
+
+    #[test]
+    fn reflect_clone_entity_works() {
+        let mut world = create_world();
+        let source_entity = world
+            .spawn((
+                ComponentC(4),
+                ComponentD {
+                    inner: InnerStruct { a: 1, b: 2 },
+                },
+                NonReflectComponent,
+            ))
+            .id();
+
+        let clone_entity = reflect_clone_entity(&mut world, source_entity, &|_| true).unwrap();
+        assert_ne!(clone_entity, source_entity);
+        assert_eq!(world.get::<ComponentC>(clone_entity).unwrap().0, 4);
+        assert_eq!(
+            world.get::<ComponentD>(clone_entity).unwrap().inner,
+            InnerStruct { a: 1, b: 2 }
+        );
+
+        // Test components that are not reflectable, or are filtered out, are not cloned
+        assert!(!world.entity(clone_entity).contains::<NonReflectComponent>());
+        let clone_entity = reflect_clone_entity(&mut world, source_entity, &|type_id| {
+            type_id != TypeId::of::<ComponentD>()
+        })
+        .unwrap();
+        assert!(world.entity(clone_entity).contains::<ComponentC>());
+        assert!(!world.entity(clone_entity).contains::<ComponentD>());
+
+        // Test the error when the source entity does not exist
+        world.despawn(source_entity);
+        assert_eq!(
+            reflect_clone_entity(&mut world, source_entity, &|_| true),
+            Err(ReflectError::EntityNotFound)
+        );
+    }
+
     #[test]
     fn reflect_copy_shared_component_props_works_with_non_reflect_components() {
         let mut world = create_world();
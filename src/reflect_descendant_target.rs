@@ -0,0 +1,186 @@
+use std::{any::TypeId, collections::VecDeque};
+
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Describes a field on a component carried by a descendant of a root entity, rather than by a known entity.
+///
+/// Created with [`ReflectTarget::new_component_in_descendants`]. Call [`ReflectDescendantTarget::resolve`] to get a
+/// [`ReflectTarget`] for the first descendant that has the component. The resolved entity is cached, and is only
+/// searched for again if it no longer has the component or is no longer a descendant of the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectDescendantTarget {
+    pub root: Entity,
+    pub type_id: TypeId,
+    pub field_path: String,
+    resolved_entity: Option<Entity>,
+}
+
+impl ReflectTarget {
+    /// Returns a target for a field on component `T`, on whichever descendant of `root` carries it.
+    pub fn new_component_in_descendants<T: Component + Reflect>(
+        root: Entity,
+        field_path: impl Into<String>,
+    ) -> ReflectDescendantTarget {
+        ReflectDescendantTarget {
+            root,
+            type_id: TypeId::of::<T>(),
+            field_path: field_path.into(),
+            resolved_entity: None,
+        }
+    }
+}
+
+impl ReflectDescendantTarget {
+    /// Returns the cached descendant entity from the last call to `resolve`, if any.
+    pub fn resolved_entity(&self) -> Option<Entity> {
+        self.resolved_entity
+    }
+
+    /// Resolves the descendant entity that carries the component, returning a [`ReflectTarget`] pointing at it.
+    ///
+    /// Descendants are searched breadth-first through their `Children`, so the closest descendant is found first. The
+    /// root entity itself is not included.
+    ///
+    /// Returns `ReflectError::EntityNotFound` if the root entity does not exist, or
+    /// `ReflectError::EntityDoesNotHaveComponent` if no descendant has the component.
+    pub fn resolve(&mut self, world: &World) -> Result<ReflectTarget, ReflectError> {
+        let cached_entity = self.resolved_entity.filter(|entity| {
+            world
+                .get_entity(*entity)
+                .is_some_and(|entity_ref| entity_ref.contains_type_id(self.type_id))
+                && self.is_descendant(world, *entity)
+        });
+        let entity = match cached_entity {
+            Some(entity) => entity,
+            None => self.find_descendant(world)?,
+        };
+        self.resolved_entity = Some(entity);
+
+        Ok(ReflectTarget::new(
+            ReflectKind::Component(entity, self.type_id),
+            self.field_path.clone(),
+        ))
+    }
+
+    /// Walks `Parent` up from an entity, returning whether the root entity is one of its ancestors.
+    fn is_descendant(&self, world: &World, entity: Entity) -> bool {
+        let mut current = entity;
+        while let Some(parent) = world.get::<Parent>(current) {
+            current = parent.get();
+            if current == self.root {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Walks the hierarchy below the root entity breadth-first, returning the first entity with the component.
+    fn find_descendant(&self, world: &World) -> Result<Entity, ReflectError> {
+        let root = world
+            .get_entity(self.root)
+            .ok_or(ReflectError::EntityNotFound)?;
+
+        let mut queue: VecDeque<Entity> = root
+            .get::<Children>()
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default();
+        while let Some(entity) = queue.pop_front() {
+            let Some(entity_ref) = world.get_entity(entity) else {
+                continue;
+            };
+            if entity_ref.contains_type_id(self.type_id) {
+                return Ok(entity);
+            }
+            if let Some(children) = entity_ref.get::<Children>() {
+                queue.extend(children.iter().copied());
+            }
+        }
+
+        Err(ReflectError::EntityDoesNotHaveComponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ComponentA>();
+
+        world
+    }
+
+    #[test]
+    fn resolve_finds_nested_descendant() {
+        let mut world = create_world();
+        let grandchild = world.spawn(ComponentA { value: 1 }).id();
+        let child = world.spawn_empty().add_child(grandchild).id();
+        let sibling = world.spawn_empty().id();
+        let root = world.spawn_empty().push_children(&[sibling, child]).id();
+
+        let mut target = ReflectTarget::new_component_in_descendants::<ComponentA>(root, "value");
+        assert_eq!(target.resolved_entity(), None);
+
+        // Test the component is found two levels down, and the entity is cached
+        let resolved = target.resolve(&world).unwrap();
+        assert_eq!(
+            resolved,
+            ReflectTarget::new_component::<ComponentA>(grandchild, "value")
+        );
+        assert_eq!(target.resolved_entity(), Some(grandchild));
+        assert_eq!(resolved.read_value::<i32>(&mut world), Ok(1));
+
+        // Test the target is resolved again when the cached entity no longer has the component
+        world.entity_mut(grandchild).remove::<ComponentA>();
+        world.entity_mut(sibling).insert(ComponentA { value: 2 });
+        let resolved = target.resolve(&world).unwrap();
+        assert_eq!(target.resolved_entity(), Some(sibling));
+        assert_eq!(resolved.read_value::<i32>(&mut world), Ok(2));
+
+        // Test the error when no descendant has the component
+        world.entity_mut(sibling).remove::<ComponentA>();
+        assert_eq!(
+            target.resolve(&world),
+            Err(ReflectError::EntityDoesNotHaveComponent)
+        );
+    }
+
+    #[test]
+    fn resolve_rechecks_reparented_entity() {
+        let mut world = create_world();
+        let child = world.spawn(ComponentA { value: 1 }).id();
+        let sibling = world.spawn_empty().id();
+        let root = world.spawn_empty().push_children(&[child, sibling]).id();
+
+        let mut target = ReflectTarget::new_component_in_descendants::<ComponentA>(root, "value");
+        target.resolve(&world).unwrap();
+        assert_eq!(target.resolved_entity(), Some(child));
+
+        // Test the target is resolved again when the cached entity is moved under another root
+        world.spawn_empty().add_child(child);
+        world.entity_mut(sibling).insert(ComponentA { value: 2 });
+        let resolved = target.resolve(&world).unwrap();
+        assert_eq!(target.resolved_entity(), Some(sibling));
+        assert_eq!(resolved.read_value::<i32>(&mut world), Ok(2));
+
+        // Test the error when the only entity with the component is no longer a descendant
+        world.entity_mut(sibling).remove::<ComponentA>();
+        assert_eq!(
+            target.resolve(&world),
+            Err(ReflectError::EntityDoesNotHaveComponent)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Resource holding a set of targets to read together, e.g. for a live debug overlay.
+///
+/// Use [`reflect_probe_snapshot`] to read the current serialized value of every target in one call.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReflectProbe {
+    pub targets: Vec<ReflectTarget>,
+}
+
+impl ReflectProbe {
+    pub fn new(targets: impl IntoIterator<Item = ReflectTarget>) -> Self {
+        Self {
+            targets: targets.into_iter().collect(),
+        }
+    }
+}
+
+/// Reads the current serialized value of every target in the probe, in order.
+///
+/// Each target has its own result, so one missing target does not prevent reading the others.
+///
+/// ```ignore
+/// fn debug_overlay(world: &World) {
+///     let probe = world.resource::<ReflectProbe>();
+///     for (target, value) in reflect_probe_snapshot(world, probe) {
+///         info!("{:?}: {value:?}", target.field_path);
+///     }
+/// }
+/// ```
+pub fn reflect_probe_snapshot(
+    world: &World,
+    probe: &ReflectProbe,
+) -> Vec<(ReflectTarget, Result<String, ReflectError>)> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    probe
+        .targets
+        .iter()
+        .map(|target| {
+            let value = target
+                .with_field(world, |field| {
+                    serialize_reflect_value(&type_registry, field)
+                })
+                .and_then(|result| result);
+            (target.clone(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: u32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<ComponentA>();
+
+        world
+    }
+
+    #[test]
+    fn reflect_probe_snapshot_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA { value: -1 });
+        let entity = world.spawn(ComponentA { value: 2 }).id();
+
+        let resource_target = ReflectTarget::new_resource::<ResourceA>("value");
+        let component_target = ReflectTarget::new_component::<ComponentA>(entity, "value");
+        let missing_target = ReflectTarget::new_resource::<ResourceA>("missing");
+        world.insert_resource(ReflectProbe::new([
+            resource_target.clone(),
+            component_target.clone(),
+            missing_target.clone(),
+        ]));
+
+        let world = &world;
+        let snapshot = reflect_probe_snapshot(world, world.resource::<ReflectProbe>());
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(
+            snapshot[0],
+            (resource_target, Ok("{\"i32\":-1}".to_string()))
+        );
+        assert_eq!(
+            snapshot[1],
+            (component_target, Ok("{\"u32\":2}".to_string()))
+        );
+        assert_eq!(snapshot[2].0, missing_target);
+        assert!(snapshot[2].1.is_err());
+    }
+}
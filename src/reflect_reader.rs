@@ -0,0 +1,89 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::*;
+
+/// System parameter for reading [`ReflectTarget`] values from regular, non-exclusive systems.
+///
+/// Reading reflected values requires readonly access to the whole world, so systems using this parameter can not run
+/// in parallel with systems that mutate the world. Setting values still requires exclusive world access, e.g. via a
+/// command or an exclusive system.
+///
+/// ```ignore
+/// fn display_volume(reader: ReflectReader) {
+///     let target = ReflectTarget::new_resource::<Settings>("volume");
+///     if let Ok(volume) = reader.read_value::<i32>(&target) {
+///         info!("Volume: {volume}");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ReflectReader<'w> {
+    world: &'w World,
+}
+
+impl<'w> ReflectReader<'w> {
+    /// Reads the value of the target field. See [`ReflectTarget::read_value`].
+    pub fn read_value<T: Reflect + Clone>(
+        &self,
+        target: &ReflectTarget,
+    ) -> Result<T, ReflectError> {
+        target.with_field(self.world, |field| {
+            field
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Reads the serialized value of the target field. See [`ReflectTarget::read_value_serialized`].
+    pub fn read_value_serialized(&self, target: &ReflectTarget) -> Result<String, ReflectError> {
+        let type_registry = self.world.resource::<AppTypeRegistry>().read();
+        target.with_field(self.world, |field| {
+            serialize_reflect_value(&type_registry, field)
+        })?
+    }
+
+    /// Returns the world the values are read from.
+    pub fn world(&self) -> &'w World {
+        self.world
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+
+        world
+    }
+
+    #[test]
+    fn reflect_reader_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA { value: 3 });
+
+        let result = world.run_system_once(|reader: ReflectReader| {
+            let target = ReflectTarget::new_resource::<ResourceA>("value");
+            (
+                reader.read_value::<i32>(&target),
+                reader.read_value_serialized(&target),
+            )
+        });
+        assert_eq!(result, (Ok(3), Ok("{\"i32\":3}".to_string())));
+    }
+}
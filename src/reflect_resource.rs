@@ -1,15 +1,22 @@
 use std::any::TypeId;
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::component::Tick,
+    prelude::*,
+    reflect::{ReflectMut, ReflectRef, TypeRegistry},
+    utils::HashMap,
+};
 
+use crate::macros::reflect_span;
 use crate::*;
 
 /// Utility that reads the value of a field on a resource by path, downcast to the provided type.
 pub fn reflect_resource_read_path<T: Reflect + Clone>(
     world: &World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<T, ReflectError> {
+    let path = path.as_ref();
     with_resource_reflect_field(world, resource_type_id, path, |field| {
         field
             .downcast_ref::<T>()
@@ -18,12 +25,103 @@ pub fn reflect_resource_read_path<T: Reflect + Clone>(
     })?
 }
 
+/// Returns the `TypeId` of the field at a path on a resource, e.g. to look up type data for the field's type.
+pub fn reflect_resource_field_type_id(
+    world: &World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<TypeId, ReflectError> {
+    with_resource_reflect_field(world, resource_type_id, path, |field| {
+        field
+            .get_represented_type_info()
+            .map(|type_info| type_info.type_id())
+            .ok_or(ReflectError::TypeRegistrationNotFound)
+    })?
+}
+
+/// Same as `reflect_resource_read_path`, but returns `ReflectError::NoAccess` instead of blocking if the
+/// `AppTypeRegistry` is locked for writing elsewhere, or panicking if its lock was poisoned.
+pub fn reflect_resource_read_path_try<T: Reflect + Clone>(
+    world: &World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<T, ReflectError> {
+    let path = path.as_ref();
+    let type_registry = world
+        .resource::<AppTypeRegistry>()
+        .internal
+        .try_read()
+        .map_err(|_| ReflectError::NoAccess)?;
+
+    with_resource_reflect_field_in_registry(
+        world,
+        &type_registry,
+        resource_type_id,
+        path,
+        |field| {
+            field
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or(ReflectError::InvalidDowncast)
+        },
+    )?
+}
+
+/// Borrowed reflected value of a field on a resource, returned by `reflect_resource_read_path_ref`.
+///
+/// The borrow is tied to the `World`, so the field can be read without cloning for as long as the world is not
+/// mutated. The type registry is only needed to look up the resource, so no lock is held while the guard is alive.
+#[derive(Clone, Copy)]
+pub struct ReflectResourceFieldRef<'w> {
+    field: &'w dyn Reflect,
+}
+
+impl<'w> ReflectResourceFieldRef<'w> {
+    /// Returns the value of the field downcast to the provided type, or `None` if it is a different type.
+    pub fn get<T: Reflect>(&self) -> Option<&'w T> {
+        self.field.downcast_ref::<T>()
+    }
+
+    /// Returns the reflected value of the field.
+    pub fn as_reflect(&self) -> &'w dyn Reflect {
+        self.field
+    }
+}
+
+impl<'w> std::ops::Deref for ReflectResourceFieldRef<'w> {
+    type Target = dyn Reflect;
+
+    fn deref(&self) -> &Self::Target {
+        self.field
+    }
+}
+
+/// Utility that borrows the reflected value of a field on a resource by path, without cloning it.
+///
+/// The path is resolved the same way as `with_resource_reflect_field`, including through `ReflectFieldAccessor` types.
+pub fn reflect_resource_read_path_ref<'w>(
+    world: &'w World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<ReflectResourceFieldRef<'w>, ReflectError> {
+    let path = path.as_ref();
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let reflect_resource = get_reflect_resource(&type_registry, resource_type_id)?;
+    let dyn_reflect: &'w dyn Reflect = reflect_resource
+        .reflect(world)
+        .ok_or(ReflectError::ResourceDoesNotExist)?;
+    let field = reflect_path_with_accessors(&type_registry, dyn_reflect, path)?;
+    Ok(ReflectResourceFieldRef { field })
+}
+
 /// Utility that reads the value of a field on a resource by path, downcast to the provided type.
 pub fn reflect_resource_read_path_serialized(
     world: &World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
 ) -> Result<String, ReflectError> {
+    let path = path.as_ref();
     with_resource_reflect_field(world, resource_type_id, path, |field| {
         let app_type_registry = world.resource::<AppTypeRegistry>();
         let type_registry = app_type_registry.read();
@@ -33,25 +131,143 @@ pub fn reflect_resource_read_path_serialized(
     })?
 }
 
+/// Utility that serializes the value of a field on a resource by path directly into a writer.
+///
+/// Produces the same output as `reflect_resource_read_path_serialized`, without building an intermediate `String`.
+pub fn reflect_resource_write_serialized(
+    world: &World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    writer: &mut impl std::io::Write,
+) -> Result<(), ReflectError> {
+    let path = path.as_ref();
+    with_resource_reflect_field(world, resource_type_id, path, |field| {
+        let app_type_registry = world.resource::<AppTypeRegistry>();
+        let type_registry = app_type_registry.read();
+
+        serialize_reflect_value_to_writer(&type_registry, field, writer)
+    })?
+}
+
+/// Cache of serialized resource field values, used by `reflect_resource_read_path_serialized_cached`.
+///
+/// Entries are keyed by the resource type and field path, and store the resource's change tick at the time the value
+/// was serialized.
+#[derive(Resource, Default, Debug)]
+pub struct SerializedCache {
+    entries: HashMap<(TypeId, String), (Tick, String)>,
+    #[cfg(test)]
+    misses: usize,
+}
+
+impl SerializedCache {
+    /// Returns the number of reads that had to serialize the value because it was not cached or had changed.
+    #[cfg(test)]
+    fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes all cached values.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Utility that reads the serialized value of a field on a resource by path, re-using the value stored in `cache` if
+/// the resource has not changed since it was last serialized.
+///
+/// Changes are detected using the resource's change tick, so changes made within the same tick as the previous read
+/// are not detected. If the cache is stored as a resource, use `World::resource_scope` to access it alongside the
+/// world.
+pub fn reflect_resource_read_path_serialized_cached(
+    world: &World,
+    cache: &mut SerializedCache,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+) -> Result<String, ReflectError> {
+    let path = path.as_ref();
+    let Ok(change_tick) = last_changed_tick(world, ResolvedReflectKind::Resource(resource_type_id))
+    else {
+        // NOTE: Read through the registry so an unregistered type is reported as such, rather than as missing.
+        return reflect_resource_read_path_serialized(world, resource_type_id, path);
+    };
+
+    let key = (resource_type_id, path.to_string());
+    if let Some((tick, serialized)) = cache.entries.get(&key) {
+        if *tick == change_tick {
+            return Ok(serialized.clone());
+        }
+    }
+
+    let serialized = reflect_resource_read_path_serialized(world, resource_type_id, path)?;
+    #[cfg(test)]
+    {
+        cache.misses += 1;
+    }
+    cache.entries.insert(key, (change_tick, serialized.clone()));
+    Ok(serialized)
+}
+
 /// Utility that sets the value of a field on a resource by path.
+///
+/// For field types that don't reflect `PartialEq`, the old and new values are serialized to detect whether the value
+/// changed. See `reflect_set_field_comparing_serialized`.
 pub fn reflect_resource_set_path<T: Reflect>(
     world: &mut World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     value: T,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     with_resource_reflect_field_mut(world, resource_type_id, path, |reflect_field| {
-        let value: Box<dyn Reflect> = Box::new(value);
-        let is_eq = reflect_field.reflect_partial_eq(value.as_reflect());
-        match is_eq {
-            Some(true) => Ok(ReflectSetSuccess::NoChanges),
-            _ => match reflect_field.set(value) {
-                Ok(_) => Ok(ReflectSetSuccess::Changed),
-                // NOTE: The error message contained below is not useful, it is usually the name of the dynamic type,
-                // e.g. "DynamicStruct".
-                Err(_) => Err(ReflectError::SetValueFailed),
-            },
-        }
+        reflect_set_field_comparing_serialized(
+            &app_type_registry.read(),
+            reflect_field,
+            Box::new(value),
+        )
+    })?
+}
+
+/// Returns the paths of all leaf fields of a resource for which `predicate` returns `true`, e.g. to find every `bool`
+/// field that is currently `true` for a settings search box.
+///
+/// Struct and tuple struct fields are walked recursively, so only leaf fields like `"audio.muted"` are returned.
+pub fn reflect_resource_find_paths_matching(
+    world: &World,
+    resource_type_id: TypeId,
+    predicate: impl Fn(&dyn Reflect) -> bool,
+) -> Result<Vec<String>, ReflectError> {
+    with_resource_reflect(world, resource_type_id, |resource| {
+        let mut paths = Vec::new();
+        visit_leaf_fields(resource, "", &mut |path,
+                                              field|
+         -> Result<(), ReflectError> {
+            if predicate(field) {
+                paths.push(path.to_string());
+            }
+            Ok(())
+        })?;
+        Ok(paths)
+    })?
+}
+
+/// Utility that sets the value of a field on a resource by path to a new value computed from the current one, without
+/// releasing the resource in between.
+///
+/// Returns `ReflectError::InvalidDowncast` if the field is not a `T`.
+pub fn reflect_resource_update_path<T: Reflect + Clone>(
+    world: &mut World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    update_fn: impl FnOnce(T) -> T,
+) -> ReflectSetResult {
+    with_resource_reflect_field_mut(world, resource_type_id, path, |reflect_field| {
+        let value = reflect_field
+            .downcast_ref::<T>()
+            .cloned()
+            .ok_or(ReflectError::InvalidDowncast)?;
+        reflect_set_field(reflect_field, Box::new(update_fn(value)))
     })?
 }
 
@@ -59,23 +275,34 @@ pub fn reflect_resource_set_path<T: Reflect>(
 pub fn reflect_resource_set_path_serialized(
     world: &mut World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     serialized_value: &str,
 ) -> ReflectSetResult {
+    let path = path.as_ref();
+    reflect_resource_set_path_serialized_with_format(
+        world,
+        resource_type_id,
+        path,
+        serialized_value,
+        SerializationFormat::Ron,
+    )
+}
+
+/// Utility that sets the value of a field on a resource by path, from a value serialized in the specified format.
+pub fn reflect_resource_set_path_serialized_with_format(
+    world: &mut World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    serialized_value: &str,
+    format: SerializationFormat,
+) -> ReflectSetResult {
+    let path = path.as_ref();
     // De-serialize the value into a `Box<dyn Reflect>`
-    let value = deserialize_reflect_value(world, serialized_value)?;
+    let value = deserialize_reflect_value_with_format(world, serialized_value, format)?;
 
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     with_resource_reflect_field_mut(world, resource_type_id, path, |reflect_field| {
-        let is_eq = reflect_field.reflect_partial_eq(value.as_reflect());
-        match is_eq {
-            Some(true) => Ok(ReflectSetSuccess::NoChanges),
-            _ => match reflect_field.set(value) {
-                Ok(_) => Ok(ReflectSetSuccess::Changed),
-                // NOTE: The error message contained below is not useful, it is usually the name of the dynamic type,
-                // e.g. "DynamicStruct".
-                Err(_) => Err(ReflectError::SetValueFailed),
-            },
-        }
+        reflect_set_field_comparing_serialized(&app_type_registry.read(), reflect_field, value)
     })?
 }
 
@@ -83,9 +310,10 @@ pub fn reflect_resource_set_path_serialized(
 pub fn reflect_resource_partial_eq_serialized(
     world: &mut World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     serialized_value: &str,
 ) -> Result<bool, ReflectError> {
+    let path = path.as_ref();
     // De-serialize the value into a `Box<dyn Reflect>`
     let value = deserialize_reflect_value(world, serialized_value)?;
 
@@ -95,9 +323,219 @@ pub fn reflect_resource_partial_eq_serialized(
     })?
 }
 
+/// Utility that compares a resource against an expected value with `reflect_partial_eq`, e.g. for test assertions.
+///
+/// Returns `ReflectError::PartialEq` if the resource's type does not support `reflect_partial_eq`.
+pub fn reflect_resource_eq(
+    world: &World,
+    resource_type_id: TypeId,
+    expected: &dyn Reflect,
+) -> Result<bool, ReflectError> {
+    with_resource_reflect(world, resource_type_id, |resource| {
+        resource
+            .reflect_partial_eq(expected)
+            .ok_or(ReflectError::PartialEq)
+    })?
+}
+
+/// Applies a serialized patch to several fields of a struct resource at once, with all-or-nothing semantics.
+///
+/// The patch is the serialized resource with only the fields to change, e.g.
+/// `{"my_game::Settings":(volume:3,muted:true)}`. See `reflect_resource_apply_patch_value` for how it is applied.
+pub fn reflect_resource_apply_patch(
+    world: &mut World,
+    resource_type_id: TypeId,
+    patch: &str,
+) -> ReflectSetResult {
+    let patch = deserialize_reflect_value(world, patch)?;
+    reflect_resource_apply_patch_value(world, resource_type_id, patch.as_reflect())
+}
+
+/// Applies the fields of a struct patch, such as a `DynamicStruct`, to a struct resource with all-or-nothing semantics.
+///
+/// The patch is first applied to a concrete copy of the resource, built with its reflected `FromReflect` or `Default`,
+/// so the resource is left unchanged if any field fails:
+/// - `ReflectError::NoDefaultValue` if the resource reflects neither `FromReflect` nor `Default`.
+/// - `ReflectError::NotAStruct` if the patch or the resource is not a struct.
+/// - `ReflectError::ReflectPath` if the resource does not have one of the patch's fields.
+/// - `ReflectError::SetValueFailed` if one of the patch's values does not match the type of its field, including an enum
+///   variant with the wrong shape.
+pub fn reflect_resource_apply_patch_value(
+    world: &mut World,
+    resource_type_id: TypeId,
+    patch: &dyn Reflect,
+) -> ReflectSetResult {
+    let ReflectRef::Struct(patch) = patch.reflect_ref() else {
+        return Err(ReflectError::NotAStruct);
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    // Stage the patch on a concrete copy of the resource, validating every field before touching the resource itself.
+    // A dynamic copy would accept any enum variant, and the final apply could then still fail on the concrete type.
+    let (staged, unchanged) = with_resource_reflect(world, resource_type_id, |resource| {
+        let mut staged = reflect_concrete_clone(&type_registry, resource)?;
+        let ReflectMut::Struct(staged_struct) = staged.reflect_mut() else {
+            return Err(ReflectError::NotAStruct);
+        };
+        for index in 0..patch.field_len() {
+            let (Some(name), Some(value)) = (patch.name_at(index), patch.field_at(index)) else {
+                continue;
+            };
+            let field = staged_struct.field_mut(name).ok_or_else(|| {
+                ReflectError::ReflectPath(format!(
+                    "The resource {} has no field named `{name}`",
+                    resource.reflect_type_path()
+                ))
+            })?;
+            field
+                .try_apply(value)
+                .map_err(|_| ReflectError::SetValueFailed)?;
+        }
+        let unchanged = staged.reflect_partial_eq(resource) == Some(true);
+        Ok((staged, unchanged))
+    })??;
+
+    if unchanged {
+        return Ok(ReflectSetSuccess::NoChanges);
+    }
+    with_resource_reflect_mut(world, resource_type_id, |mut resource| {
+        resource
+            .try_apply(staged.as_reflect())
+            .map_err(|_| ReflectError::SetValueFailed)
+    })??;
+    Ok(ReflectSetSuccess::Changed)
+}
+
+/// Applies a serialized struct that may be missing fields to a resource, filling any missing fields from the type's
+/// reflected default rather than keeping their current values, e.g. when loading a save from an older version.
+///
+/// Returns `ReflectError::NoDefaultValue` if the type does not reflect `Default`, `ReflectError::InvalidDowncast` if the
+/// value is not the resource's type, or `ReflectError::SetValueFailed` if one of its fields does not match.
+pub fn reflect_resource_apply_with_defaults(
+    world: &mut World,
+    resource_type_id: TypeId,
+    partial: &str,
+) -> ReflectSetResult {
+    let partial = deserialize_reflect_value(world, partial)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    // Build the complete value by applying the partial value on top of the default
+    let mut value = reflect_default_value(&type_registry, resource_type_id)?;
+    if represented_type_path(value.as_reflect()) != represented_type_path(partial.as_reflect()) {
+        return Err(ReflectError::InvalidDowncast);
+    }
+    value
+        .try_apply(partial.as_reflect())
+        .map_err(|_| ReflectError::SetValueFailed)?;
+
+    let unchanged = with_resource_reflect(world, resource_type_id, |resource| {
+        value.reflect_partial_eq(resource) == Some(true)
+    })?;
+    if unchanged {
+        return Ok(ReflectSetSuccess::NoChanges);
+    }
+    with_resource_reflect_mut(world, resource_type_id, |mut resource| {
+        resource.apply(value.as_reflect());
+    })?;
+    Ok(ReflectSetSuccess::Changed)
+}
+
+/// Applies a new value to a resource, only writing the leaf fields that differ from the current value.
+///
+/// The resource is left untouched, and not marked as changed, if no fields differ. See `reflect_diff_paths` for how
+/// the values are compared. Every differing field is staged before any is written, so the resource is also left
+/// untouched if one of them fails:
+/// - `ReflectError::InvalidDowncast` if the new value represents a different type.
+/// - `ReflectError::SetValueFailed` if one of the new values does not match the type of its field.
+pub fn reflect_resource_apply_minimal(
+    world: &mut World,
+    resource_type_id: TypeId,
+    new_value: &dyn Reflect,
+) -> ReflectSetResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let staged = with_resource_reflect(world, resource_type_id, |resource| {
+        if !is_same_represented_type(resource, new_value) {
+            return Err(ReflectError::InvalidDowncast);
+        }
+        reflect_diff_paths(&type_registry, resource, new_value)?
+            .into_iter()
+            .map(|path| {
+                let field = resource
+                    .reflect_path(path.as_str())
+                    .map_err(|err| reflect_path_error(resource, &path, err))?;
+                let new_field = new_value
+                    .reflect_path(path.as_str())
+                    .map_err(|err| reflect_path_error(new_value, &path, err))?;
+                let mut staged_field = reflect_concrete_clone(&type_registry, field)?;
+                staged_field
+                    .try_apply(new_field)
+                    .map_err(|_| ReflectError::SetValueFailed)?;
+                Ok((path, staged_field))
+            })
+            .collect::<Result<Vec<_>, ReflectError>>()
+    })??;
+    if staged.is_empty() {
+        return Ok(ReflectSetSuccess::NoChanges);
+    }
+
+    with_resource_reflect_mut(world, resource_type_id, |mut resource| {
+        for (path, staged_field) in staged.iter() {
+            resource
+                .reflect_path_mut(path.as_str())
+                .map_err(|err| ReflectError::ReflectPath(err.to_string()))?
+                .try_apply(staged_field.as_reflect())
+                .map_err(|_| ReflectError::SetValueFailed)?;
+        }
+        Ok(ReflectSetSuccess::Changed)
+    })?
+}
+
+/// Utility that copies the value of a field on one resource to a field on another resource.
+///
+/// The resources may be different types, but the fields must be the same type, otherwise
+/// `ReflectError::InvalidDowncast` is returned.
+pub fn reflect_copy_field(
+    world: &mut World,
+    src_type_id: TypeId,
+    src_path: impl AsRef<str>,
+    dst_type_id: TypeId,
+    dst_path: impl AsRef<str>,
+) -> ReflectSetResult {
+    let src_path = src_path.as_ref();
+    let dst_path = dst_path.as_ref();
+    let value =
+        with_resource_reflect_field(world, src_type_id, src_path, |field| field.clone_value())?;
+
+    with_resource_reflect_field_mut(world, dst_type_id, dst_path, |reflect_field| {
+        // NOTE: `clone_value` may return a dynamic type, so compare against the type it represents
+        let value_type_id = value.get_represented_type_info().map(|info| info.type_id());
+        let field_type_id = reflect_field
+            .get_represented_type_info()
+            .map(|info| info.type_id());
+        if value_type_id.is_none() || value_type_id != field_type_id {
+            return Err(ReflectError::InvalidDowncast);
+        }
+        match reflect_field.reflect_partial_eq(value.as_reflect()) {
+            Some(true) => Ok(ReflectSetSuccess::NoChanges),
+            _ => {
+                reflect_field.apply(value.as_reflect());
+                Ok(ReflectSetSuccess::Changed)
+            }
+        }
+    })?
+}
+
 /// Runs a closure with readonly access to a reflected resource.
 ///
-/// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
+/// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise. The type
+/// must be registered before the resource is looked up, so an unregistered type returns
+/// `ReflectError::TypeRegistrationNotFound` even if the resource is absent, and `ReflectError::ResourceDoesNotExist` is
+/// only returned for a registered resource that was not inserted.
 pub fn with_resource_reflect<T>(
     world: &World,
     resource_type_id: TypeId,
@@ -105,12 +543,7 @@ pub fn with_resource_reflect<T>(
 ) -> Result<T, ReflectError> {
     let type_registry = world.resource::<AppTypeRegistry>().read();
 
-    let registration = type_registry
-        .get(resource_type_id)
-        .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_resource = registration
-        .data::<ReflectResource>()
-        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
+    let reflect_resource = get_reflect_resource(&type_registry, resource_type_id)?;
     reflect_resource
         .reflect(world)
         .ok_or(ReflectError::ResourceDoesNotExist)
@@ -128,12 +561,7 @@ pub fn with_resource_reflect_mut<T>(
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    let registration = type_registry
-        .get(resource_type_id)
-        .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_resource = registration
-        .data::<ReflectResource>()
-        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
+    let reflect_resource = get_reflect_resource(&type_registry, resource_type_id)?;
     reflect_resource
         .reflect_mut(world)
         .ok_or(ReflectError::ResourceDoesNotExist)
@@ -163,31 +591,36 @@ pub fn with_resource_reflect_mut<T>(
 pub fn with_resource_reflect_field_mut<T>(
     world: &mut World,
     resource_type_id: TypeId,
-    path: &str,
+    path: impl AsRef<str>,
     update_fn: impl FnOnce(&mut dyn Reflect) -> T,
 ) -> Result<T, ReflectError> {
+    let path = path.as_ref();
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
+    reflect_span!(
+        "with_resource_reflect_field_mut",
+        type_path = type_registry
+            .get_type_info(resource_type_id)
+            .map_or("", |info| info.type_path()),
+        path
+    );
 
-    let registration = type_registry
-        .get(resource_type_id)
-        .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_resource = registration
-        .data::<ReflectResource>()
-        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
+    let reflect_resource = get_reflect_resource(&type_registry, resource_type_id)?;
     let mut dyn_reflect = reflect_resource
         .reflect_mut(world)
         .ok_or(ReflectError::ResourceDoesNotExist)?;
-    dyn_reflect
-        .reflect_path_mut(path)
-        .map_err(|err| ReflectError::ReflectPath(err.to_string()))
-        .map(update_fn)
+    match dyn_reflect.reflect_path_mut(path) {
+        Ok(reflect_field) => Ok(update_fn(reflect_field)),
+        Err(err) => Err(reflect_path_error(dyn_reflect.as_reflect(), path, err)),
+    }
 }
 
 /// Runs a closure with the readonly reflected value of a path on a resource.
 ///
 /// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
 ///
+/// Paths continue into the inner value of wrapper types with `ReflectFieldAccessor` type data.
+///
 /// ```ignore
 /// let result: Result<Option<i32>, ReflectError> = with_resource_reflect_field(
 ///     &world,
@@ -207,19 +640,39 @@ pub fn with_resource_reflect_field_mut<T>(
 pub fn with_resource_reflect_field<T>(
     world: &World,
     resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    read_fn: impl FnOnce(&dyn Reflect) -> T,
+) -> Result<T, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    with_resource_reflect_field_in_registry(
+        world,
+        &type_registry,
+        resource_type_id,
+        path.as_ref(),
+        read_fn,
+    )
+}
+
+/// Resolves a path on a resource using an already locked type registry, and runs a closure with the field.
+fn with_resource_reflect_field_in_registry<T>(
+    world: &World,
+    type_registry: &TypeRegistry,
+    resource_type_id: TypeId,
     path: &str,
     read_fn: impl FnOnce(&dyn Reflect) -> T,
 ) -> Result<T, ReflectError> {
-    with_resource_reflect(world, resource_type_id, |dyn_reflect| {
-        dyn_reflect
-            .reflect_path(path)
-            .map_err(|err| ReflectError::ReflectPath(err.to_string()))
-            .map(read_fn)
-    })?
+    let reflect_resource = get_reflect_resource(type_registry, resource_type_id)?;
+    let dyn_reflect = reflect_resource
+        .reflect(world)
+        .ok_or(ReflectError::ResourceDoesNotExist)?;
+    reflect_path_with_accessors(type_registry, dyn_reflect, path).map(read_fn)
 }
 
 #[cfg(test)]
 mod tests {
+    use bevy::reflect::{DynamicEnum, DynamicStruct, DynamicVariant};
+    use serde::{Deserialize, Serialize};
+
     use super::*;
 
     // NOTE: Must derive Reflect to be reflectable
@@ -246,9 +699,65 @@ mod tests {
     #[reflect(Resource)]
     struct ResourceC(u32);
 
+    #[derive(Resource, Reflect, Default, Clone)]
+    #[reflect(Resource)]
+    struct ResourceD {
+        level: EnumA,
+    }
+
+    // NOTE: Does not reflect `PartialEq`, so `reflect_partial_eq` returns `None`
+    #[derive(Reflect, Default, Clone, Serialize, Deserialize)]
+    #[reflect_value(Default, Serialize, Deserialize)]
+    struct OpaqueValue(u32);
+
+    #[derive(Resource, Reflect, Default, Clone)]
+    #[reflect(Resource)]
+    struct ResourceE {
+        value: OpaqueValue,
+    }
+
+    #[derive(Resource, Reflect, Clone)]
+    #[reflect(Resource, Default)]
+    struct ResourceF {
+        volume: u32,
+        muted: bool,
+        theme: EnumA,
+    }
+
+    #[derive(Reflect, Default, Clone)]
+    struct AudioFlags {
+        music: bool,
+        sfx: bool,
+    }
+
+    #[derive(Resource, Reflect, Default, Clone)]
+    #[reflect(Resource)]
+    struct ResourceG {
+        fullscreen: bool,
+        volume: u32,
+        audio: AudioFlags,
+        scale: ResourceC,
+    }
+
+    impl Default for ResourceF {
+        fn default() -> Self {
+            Self {
+                volume: 5,
+                muted: false,
+                theme: EnumA::A,
+            }
+        }
+    }
+
     #[derive(Resource)]
     struct NonReflectResource;
 
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentOnly {
+        value: u32,
+    }
+
     /// Test utility that creates a new world and registers the test types
     fn create_world() -> World {
         let mut world = World::new();
@@ -258,6 +767,12 @@ mod tests {
         type_registry.write().register::<ResourceA>();
         type_registry.write().register::<ResourceB>();
         type_registry.write().register::<ResourceC>();
+        type_registry.write().register::<ResourceD>();
+        type_registry.write().register::<ResourceE>();
+        type_registry.write().register::<OpaqueValue>();
+        type_registry.write().register::<ComponentOnly>();
+        type_registry.write().register::<ResourceF>();
+        type_registry.write().register::<ResourceG>();
 
         world
     }
@@ -293,6 +808,426 @@ mod tests {
         assert_eq!(resource_c_value, resource_c.0);
     }
 
+    #[test]
+    fn reflect_resource_field_type_id_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        world.init_resource::<ResourceC>();
+
+        assert_eq!(
+            reflect_resource_field_type_id(&world, TypeId::of::<ResourceA>(), "value1"),
+            Ok(TypeId::of::<EnumA>())
+        );
+        assert_eq!(
+            reflect_resource_field_type_id(&world, TypeId::of::<ResourceC>(), "0"),
+            Ok(TypeId::of::<u32>())
+        );
+        assert!(matches!(
+            reflect_resource_field_type_id(&world, TypeId::of::<ResourceA>(), "missing"),
+            Err(ReflectError::ReflectPath(_))
+        ));
+    }
+
+    #[test]
+    fn reflect_resource_find_paths_matching_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceG {
+            fullscreen: true,
+            volume: 5,
+            audio: AudioFlags {
+                music: false,
+                sfx: true,
+            },
+            scale: ResourceC(5),
+        });
+
+        // Test finding all fields equal to a value, including nested struct and tuple struct fields
+        assert_eq!(
+            reflect_resource_find_paths_matching(&world, TypeId::of::<ResourceG>(), |field| {
+                field.reflect_partial_eq(&5_u32) == Some(true)
+            }),
+            Ok(vec!["volume".to_string(), "scale.0".to_string()])
+        );
+        assert_eq!(
+            reflect_resource_find_paths_matching(&world, TypeId::of::<ResourceG>(), |field| {
+                field.downcast_ref::<bool>() == Some(&true)
+            }),
+            Ok(vec!["fullscreen".to_string(), "audio.sfx".to_string()])
+        );
+
+        // Test the error when the resource does not exist
+        assert_eq!(
+            reflect_resource_find_paths_matching(&world, TypeId::of::<ResourceA>(), |_| true),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn reflect_resource_eq_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::B(1),
+            value2: EnumA::A,
+        });
+
+        // Test equality and inequality against an expected value
+        let expected = ResourceA {
+            value1: EnumA::B(1),
+            value2: EnumA::A,
+        };
+        assert_eq!(
+            reflect_resource_eq(&world, TypeId::of::<ResourceA>(), &expected),
+            Ok(true)
+        );
+        let expected = ResourceA {
+            value1: EnumA::B(2),
+            value2: EnumA::A,
+        };
+        assert_eq!(
+            reflect_resource_eq(&world, TypeId::of::<ResourceA>(), &expected),
+            Ok(false)
+        );
+
+        // Test the error when the resource does not exist
+        assert_eq!(
+            reflect_resource_eq(&world, TypeId::of::<ResourceB>(), &ResourceB),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn reflect_resource_read_path_try_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(2));
+
+        assert_eq!(
+            reflect_resource_read_path_try::<u32>(&world, TypeId::of::<ResourceC>(), "0"),
+            Ok(2)
+        );
+
+        // Test `NoAccess` is returned instead of blocking while the registry is locked for writing
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let guard = app_type_registry.write();
+        assert_eq!(
+            reflect_resource_read_path_try::<u32>(&world, TypeId::of::<ResourceC>(), "0"),
+            Err(ReflectError::NoAccess)
+        );
+        drop(guard);
+    }
+
+    #[test]
+    fn reflect_resource_apply_patch_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+
+        // Test a patch with a subset of the fields
+        let result = reflect_resource_apply_patch(
+            &mut world,
+            TypeId::of::<ResourceA>(),
+            "{\"bevy_reflect_utils::reflect_resource::tests::ResourceA\":(value1:B(3))}",
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        let resource = world.resource::<ResourceA>();
+        assert_eq!(
+            (resource.value1, resource.value2),
+            (EnumA::B(3), EnumA::B(1))
+        );
+
+        // Test a patch with one invalid field leaves the resource unchanged
+        let mut patch = DynamicStruct::default();
+        patch.insert("value1", EnumA::B(7));
+        patch.insert("value2", 5_u32);
+        let result =
+            reflect_resource_apply_patch_value(&mut world, TypeId::of::<ResourceA>(), &patch);
+        assert_eq!(result, Err(ReflectError::SetValueFailed));
+        let resource = world.resource::<ResourceA>();
+        assert_eq!(
+            (resource.value1, resource.value2),
+            (EnumA::B(3), EnumA::B(1))
+        );
+
+        // Test a patch with a field the resource does not have leaves the resource unchanged
+        let mut patch = DynamicStruct::default();
+        patch.insert("value1", EnumA::A);
+        patch.insert("missing", 5_u32);
+        let result =
+            reflect_resource_apply_patch_value(&mut world, TypeId::of::<ResourceA>(), &patch);
+        assert!(matches!(result, Err(ReflectError::ReflectPath(_))));
+        assert_eq!(world.resource::<ResourceA>().value1, EnumA::B(3));
+
+        // Test applying the same values again reports no changes
+        let mut patch = DynamicStruct::default();
+        patch.insert("value1", EnumA::B(3));
+        let result =
+            reflect_resource_apply_patch_value(&mut world, TypeId::of::<ResourceA>(), &patch);
+        assert_eq!(result, Ok(ReflectSetSuccess::NoChanges));
+    }
+
+    #[test]
+    fn reflect_resource_apply_patch_rejects_mismatched_variant_shape() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+
+        // Test a unit `B` variant, when `B` is a tuple variant, leaves the resource unchanged instead of panicking
+        let mut patch = DynamicStruct::default();
+        patch.insert("value1", DynamicEnum::new("B", DynamicVariant::Unit));
+        patch.insert("value2", EnumA::A);
+        let result =
+            reflect_resource_apply_patch_value(&mut world, TypeId::of::<ResourceA>(), &patch);
+        assert_eq!(result, Err(ReflectError::SetValueFailed));
+        let resource = world.resource::<ResourceA>();
+        assert_eq!((resource.value1, resource.value2), (EnumA::A, EnumA::B(1)));
+    }
+
+    #[test]
+    fn reflect_resource_apply_with_defaults_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceF {
+            volume: 1,
+            muted: true,
+            theme: EnumA::B(2),
+        });
+
+        // Test the missing `muted` field takes its default, rather than keeping its current value
+        let result = reflect_resource_apply_with_defaults(
+            &mut world,
+            TypeId::of::<ResourceF>(),
+            "{\"bevy_reflect_utils::reflect_resource::tests::ResourceF\":(volume:3,theme:B(4))}",
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        let resource = world.resource::<ResourceF>();
+        assert_eq!(
+            (resource.volume, resource.muted, resource.theme),
+            (3, false, EnumA::B(4))
+        );
+
+        // Test the error when the value is a different type
+        let result = reflect_resource_apply_with_defaults(
+            &mut world,
+            TypeId::of::<ResourceF>(),
+            "{\"bevy_reflect_utils::reflect_resource::tests::ResourceD\":(level:A)}",
+        );
+        assert_eq!(result, Err(ReflectError::InvalidDowncast));
+
+        // Test the error when the type does not reflect `Default`
+        world.insert_resource(ResourceD { level: EnumA::A });
+        let result = reflect_resource_apply_with_defaults(
+            &mut world,
+            TypeId::of::<ResourceD>(),
+            "{\"bevy_reflect_utils::reflect_resource::tests::ResourceD\":(level:A)}",
+        );
+        assert_eq!(result, Err(ReflectError::NoDefaultValue));
+    }
+
+    #[test]
+    fn reflect_resource_write_serialized_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let result = reflect_resource_write_serialized(
+            &world,
+            TypeId::of::<ResourceA>(),
+            "value2",
+            &mut buffer,
+        );
+        assert_eq!(result, Ok(()));
+
+        // Test the written value matches the string form
+        let expected =
+            reflect_resource_read_path_serialized(&world, TypeId::of::<ResourceA>(), "value2");
+        assert_eq!(Ok(String::from_utf8(buffer).unwrap()), expected);
+    }
+
+    #[test]
+    fn reflect_resource_read_path_serialized_cached_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(2));
+        let mut cache = SerializedCache::default();
+
+        let read = |world: &World, cache: &mut SerializedCache| {
+            reflect_resource_read_path_serialized_cached(
+                world,
+                cache,
+                TypeId::of::<ResourceC>(),
+                "0",
+            )
+        };
+
+        // Test the first read serializes the value
+        assert_eq!(read(&world, &mut cache), Ok("{\"u32\":2}".to_string()));
+        assert_eq!(cache.misses(), 1);
+
+        // Test reading an unchanged resource returns the cached value
+        assert_eq!(read(&world, &mut cache), Ok("{\"u32\":2}".to_string()));
+        assert_eq!(cache.misses(), 1);
+
+        // Test changing the resource in a later tick re-serializes the value
+        world.increment_change_tick();
+        world.resource_mut::<ResourceC>().0 = 3;
+        assert_eq!(read(&world, &mut cache), Ok("{\"u32\":3}".to_string()));
+        assert_eq!(cache.misses(), 2);
+
+        // Test the error when the resource does not exist
+        assert_eq!(
+            reflect_resource_read_path_serialized_cached(
+                &world,
+                &mut cache,
+                TypeId::of::<ResourceD>(),
+                "level"
+            ),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn reflect_resource_apply_minimal_works() {
+        #[derive(Resource, Default)]
+        struct ChangeCount(u32);
+
+        fn count_changes(resource: Res<ResourceA>, mut count: ResMut<ChangeCount>) {
+            if resource.is_changed() {
+                count.0 += 1;
+            }
+        }
+
+        let mut world = create_world();
+        world.init_resource::<ChangeCount>();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+
+        // Use a system as a probe that counts how often the resource was seen to change
+        let mut schedule = Schedule::default();
+        schedule.add_systems(count_changes);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangeCount>().0, 1);
+
+        // Test only the differing field is reported and written
+        let new_value = ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(5),
+        };
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        assert_eq!(
+            reflect_diff_paths(&type_registry, world.resource::<ResourceA>(), &new_value),
+            Ok(vec!["value2".to_string()])
+        );
+        drop(type_registry);
+        world.increment_change_tick();
+        assert_eq!(
+            reflect_resource_apply_minimal(&mut world, TypeId::of::<ResourceA>(), &new_value),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().value2, EnumA::B(5));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangeCount>().0, 2);
+
+        // Test applying an identical value does not mark the resource as changed
+        world.increment_change_tick();
+        assert_eq!(
+            reflect_resource_apply_minimal(&mut world, TypeId::of::<ResourceA>(), &new_value),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangeCount>().0, 2);
+
+        // Test the first leaf is not written, and the resource not marked as changed, when the second leaf fails
+        let mut patch = DynamicStruct::default();
+        patch.insert("value1", EnumA::B(9));
+        patch.insert("value2", "text".to_string());
+        world.increment_change_tick();
+        assert_eq!(
+            reflect_resource_apply_minimal(&mut world, TypeId::of::<ResourceA>(), &patch),
+            Err(ReflectError::SetValueFailed)
+        );
+        assert_eq!(world.resource::<ResourceA>().value1, EnumA::A);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangeCount>().0, 2);
+
+        // Test the error when the new value is a different type
+        assert_eq!(
+            reflect_resource_apply_minimal(
+                &mut world,
+                TypeId::of::<ResourceA>(),
+                &ResourceD { level: EnumA::A }
+            ),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn reflect_resource_set_path_detects_no_changes_without_partial_eq() {
+        let mut world = create_world();
+        world.insert_resource(ResourceE {
+            value: OpaqueValue(1),
+        });
+
+        // Test setting the same value reports no changes
+        let result = reflect_resource_set_path(
+            &mut world,
+            TypeId::of::<ResourceE>(),
+            "value",
+            OpaqueValue(1),
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::NoChanges));
+
+        // Test setting a different value reports changes
+        let result = reflect_resource_set_path(
+            &mut world,
+            TypeId::of::<ResourceE>(),
+            "value",
+            OpaqueValue(2),
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        assert_eq!(world.resource::<ResourceE>().value.0, 2);
+    }
+
+    #[test]
+    fn reflect_resource_read_path_ref_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+
+        // Test holding the guard and reading the field through it
+        let field_ref =
+            reflect_resource_read_path_ref(&world, TypeId::of::<ResourceA>(), "value2").unwrap();
+        assert_eq!(field_ref.get::<EnumA>(), Some(&EnumA::B(1)));
+        assert_eq!(field_ref.get::<u32>(), None);
+        assert_eq!(field_ref.reflect_type_path(), EnumA::type_path());
+        drop(field_ref);
+
+        // Test the world can be mutated after the guard is dropped
+        world.resource_mut::<ResourceA>().value2 = EnumA::A;
+
+        // Test the error when the path is invalid
+        let result = reflect_resource_read_path_ref(&world, TypeId::of::<ResourceA>(), "invalid");
+        assert!(matches!(result, Err(ReflectError::ReflectPath(_))));
+    }
+
+    #[test]
+    fn reflect_resource_read_path_accepts_owned_string() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(2));
+
+        // Test passing the path as an owned `String`
+        let path = 0.to_string();
+        let value = reflect_resource_read_path::<u32>(&world, TypeId::of::<ResourceC>(), path);
+        assert_eq!(value, Ok(2));
+    }
+
     #[test]
     fn reflect_resource_read_path_errors() {
         let mut world = create_world();
@@ -318,6 +1253,15 @@ mod tests {
         let result = reflect_resource_read_path::<u32>(&world, TypeId::of::<ResourceA>(), "value1");
         assert!(matches!(result, Err(ReflectError::InvalidDowncast)));
 
+        // Test the error when a tuple index is out of range
+        let result = reflect_resource_read_path::<u32>(&world, TypeId::of::<ResourceC>(), "1");
+        assert_eq!(
+            result,
+            Err(ReflectError::ReflectPath(
+                "index 1 out of range for tuple of arity 1".to_string()
+            ))
+        );
+
         // Test the error when the type is not registered
         let result =
             reflect_resource_read_path::<u32>(&world, TypeId::of::<NonReflectResource>(), "value1");
@@ -327,6 +1271,106 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn unregistered_and_missing_resources_return_distinct_errors() {
+        let mut world = create_world();
+        world.insert_resource(NonReflectResource);
+        let mut cache = SerializedCache::default();
+
+        // Test a registered resource that was not inserted
+        assert_eq!(
+            with_resource_reflect(&world, TypeId::of::<ResourceB>(), |_| ()),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+        assert_eq!(
+            with_resource_reflect_mut(&mut world, TypeId::of::<ResourceB>(), |_| ()),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+        assert_eq!(
+            reflect_resource_read_path_serialized_cached(
+                &world,
+                &mut cache,
+                TypeId::of::<ResourceB>(),
+                ""
+            ),
+            Err(ReflectError::ResourceDoesNotExist)
+        );
+
+        // Test an inserted resource whose type was never registered
+        assert_eq!(
+            with_resource_reflect(&world, TypeId::of::<NonReflectResource>(), |_| ()),
+            Err(ReflectError::TypeRegistrationNotFound)
+        );
+        assert_eq!(
+            with_resource_reflect_mut(&mut world, TypeId::of::<NonReflectResource>(), |_| ()),
+            Err(ReflectError::TypeRegistrationNotFound)
+        );
+
+        // Test a type that was neither registered nor inserted
+        world.remove_resource::<NonReflectResource>();
+        assert_eq!(
+            reflect_resource_read_path_serialized_cached(
+                &world,
+                &mut cache,
+                TypeId::of::<NonReflectResource>(),
+                ""
+            ),
+            Err(ReflectError::TypeRegistrationNotFound)
+        );
+    }
+
+    #[test]
+    fn component_used_as_resource_names_registered_type_data() {
+        let mut world = create_world();
+
+        // Test the error says which type data was expected and which was found
+        let result =
+            reflect_resource_set_path(&mut world, TypeId::of::<ComponentOnly>(), "value", 1u32);
+        assert_eq!(
+            result,
+            Err(ReflectError::TypeDataMismatch {
+                expected: "ReflectResource",
+                found: "ReflectComponent",
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Expected ReflectResource but only ReflectComponent is registered"
+        );
+
+        // Test the reverse, using a resource as a component target
+        let entity = world.spawn_empty().id();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        assert_eq!(
+            reflect_component_read_path::<u32>(
+                &world.entity(entity),
+                &type_registry,
+                TypeId::of::<ResourceC>(),
+                "0"
+            ),
+            Err(ReflectError::TypeDataMismatch {
+                expected: "ReflectComponent",
+                found: "ReflectResource",
+            })
+        );
+    }
+
+    #[test]
+    fn reflect_resource_set_path_with_field_name_on_tuple_struct_errors() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(2));
+
+        // Test the error explains that tuple structs are indexed by number
+        assert_eq!(
+            reflect_resource_set_path(&mut world, TypeId::of::<ResourceC>(), "value", 3u32),
+            Err(ReflectError::ReflectPath(
+                "`ResourceC` is a tuple struct, use a numeric index like \"0\" instead of the field name \"value\""
+                    .to_string()
+            ))
+        );
+        assert_eq!(world.resource::<ResourceC>().0, 2);
+    }
+
     #[test]
     fn reflect_resource_set_path_works() {
         let mut world = create_world();
@@ -363,4 +1407,98 @@ mod tests {
             original_resource_a.value2
         );
     }
+
+    #[test]
+    fn reflect_resource_set_path_serialized_with_format_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(0));
+
+        // Test we can set the value of ResourceC::0 from a RON string
+        let result = reflect_resource_set_path_serialized_with_format(
+            &mut world,
+            TypeId::of::<ResourceC>(),
+            "0",
+            "{\"u32\":5}",
+            SerializationFormat::Ron,
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        assert_eq!(world.resource::<ResourceC>().0, 5);
+
+        // Test we can set the value of ResourceC::0 from a JSON string
+        #[cfg(feature = "json")]
+        {
+            let result = reflect_resource_set_path_serialized_with_format(
+                &mut world,
+                TypeId::of::<ResourceC>(),
+                "0",
+                "{\"u32\": 7}",
+                SerializationFormat::Json,
+            );
+            assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+            assert_eq!(world.resource::<ResourceC>().0, 7);
+        }
+
+        // Test the error for a JSON string when the `json` feature is disabled
+        #[cfg(not(feature = "json"))]
+        {
+            let result = reflect_resource_set_path_serialized_with_format(
+                &mut world,
+                TypeId::of::<ResourceC>(),
+                "0",
+                "{\"u32\": 7}",
+                SerializationFormat::Json,
+            );
+            assert!(matches!(result, Err(ReflectError::Deserialize(_))));
+            assert_eq!(world.resource::<ResourceC>().0, 5);
+        }
+    }
+
+    #[test]
+    fn reflect_copy_field_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value1: EnumA::A,
+            value2: EnumA::B(1),
+        });
+        world.insert_resource(ResourceD { level: EnumA::A });
+
+        // Test we can copy `ResourceA::value2` to `ResourceD::level`
+        let result = reflect_copy_field(
+            &mut world,
+            TypeId::of::<ResourceA>(),
+            "value2",
+            TypeId::of::<ResourceD>(),
+            "level",
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+        assert_eq!(world.resource::<ResourceD>().level, EnumA::B(1));
+
+        // Test copying the same value again returns `NoChanges`
+        let result = reflect_copy_field(
+            &mut world,
+            TypeId::of::<ResourceA>(),
+            "value2",
+            TypeId::of::<ResourceD>(),
+            "level",
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::NoChanges));
+    }
+
+    #[test]
+    fn reflect_copy_field_errors() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC(2));
+        world.insert_resource(ResourceD { level: EnumA::A });
+
+        // Test the error when the fields are different types
+        let result = reflect_copy_field(
+            &mut world,
+            TypeId::of::<ResourceC>(),
+            "0",
+            TypeId::of::<ResourceD>(),
+            "level",
+        );
+        assert_eq!(result, Err(ReflectError::InvalidDowncast));
+        assert_eq!(world.resource::<ResourceD>().level, EnumA::A);
+    }
 }
@@ -0,0 +1,147 @@
+use std::any::TypeId;
+
+use bevy::{prelude::*, reflect::TypeRegistry};
+
+use crate::*;
+
+/// Set the value of a field by its path on a component of an entity in a [`DynamicScene`].
+///
+/// `entity_index` is the index of the entity in `DynamicScene::entities`. Components in a scene are usually dynamic
+/// types, so they are matched against `component_type_id` using the type they represent.
+pub fn reflect_dynamic_scene_set_path<T: Reflect>(
+    scene: &mut DynamicScene,
+    type_registry: &TypeRegistry,
+    entity_index: usize,
+    component_type_id: TypeId,
+    path: impl AsRef<str>,
+    value: T,
+) -> ReflectSetResult {
+    let path = path.as_ref();
+    with_dynamic_scene_component_field_mut(
+        scene,
+        type_registry,
+        entity_index,
+        component_type_id,
+        path,
+        |reflect_field| reflect_set_field(reflect_field, Box::new(value)),
+    )?
+}
+
+/// Runs a closure with mutable access to the reflected value of a path on a component of an entity in a
+/// [`DynamicScene`].
+///
+/// Returns a `Result` containing the return value of the closure if successful, `ReflectError` otherwise.
+pub fn with_dynamic_scene_component_field_mut<T>(
+    scene: &mut DynamicScene,
+    type_registry: &TypeRegistry,
+    entity_index: usize,
+    component_type_id: TypeId,
+    field_path: impl AsRef<str>,
+    update_fn: impl FnOnce(&mut dyn Reflect) -> T,
+) -> Result<T, ReflectError> {
+    let field_path = field_path.as_ref();
+    type_registry
+        .get(component_type_id)
+        .ok_or(ReflectError::TypeRegistrationNotFound)?;
+
+    let dynamic_entity = scene
+        .entities
+        .get_mut(entity_index)
+        .ok_or(ReflectError::EntityNotFound)?;
+    let component = dynamic_entity
+        .components
+        .iter_mut()
+        .find(|component| {
+            component
+                .get_represented_type_info()
+                .map(|type_info| type_info.type_id())
+                == Some(component_type_id)
+        })
+        .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
+
+    match component.reflect_path_mut(field_path) {
+        Ok(reflect_field) => Ok(update_fn(reflect_field)),
+        Err(err) => Err(reflect_path_error(component.as_reflect(), field_path, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::entity::EntityHashMap;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentB;
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ComponentA>();
+        type_registry.write().register::<ComponentB>();
+
+        world
+    }
+
+    #[test]
+    fn reflect_dynamic_scene_set_path_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentA { value: 1 }).id();
+        let mut scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(entity)
+            .build();
+
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = app_type_registry.read();
+
+        // Test setting the value on the scene
+        let result = reflect_dynamic_scene_set_path(
+            &mut scene,
+            &type_registry,
+            0,
+            TypeId::of::<ComponentA>(),
+            "value",
+            5_i32,
+        );
+        assert_eq!(result, Ok(ReflectSetSuccess::Changed));
+
+        // Test the errors for a missing entity and component
+        let result = reflect_dynamic_scene_set_path(
+            &mut scene,
+            &type_registry,
+            1,
+            TypeId::of::<ComponentA>(),
+            "value",
+            5_i32,
+        );
+        assert_eq!(result, Err(ReflectError::EntityNotFound));
+        let result = reflect_dynamic_scene_set_path(
+            &mut scene,
+            &type_registry,
+            0,
+            TypeId::of::<ComponentB>(),
+            "value",
+            5_i32,
+        );
+        assert_eq!(result, Err(ReflectError::EntityDoesNotHaveComponent));
+
+        // Test the value is set after spawning the scene
+        let mut new_world = create_world();
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut new_world, &mut entity_map)
+            .unwrap();
+        let new_entity = entity_map[&entity];
+        assert_eq!(new_world.get::<ComponentA>(new_entity).unwrap().value, 5);
+    }
+}
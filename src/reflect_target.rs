@@ -1,80 +1,756 @@
-use std::any::TypeId;
+use core::fmt;
+use std::{any::TypeId, borrow::Cow, ops::RangeInclusive, time::Duration};
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    reflect::{Access, GetTypeRegistration, ParsedPath, ReflectMut, ReflectRef, TypeInfo},
+};
 
 use crate::*;
 
 /// Type describing the target kind for a [`ReflectTarget`].
-#[derive(Debug, Clone, Copy)]
+///
+/// Non-exhaustive, so new kinds of targets can be added without breaking downstream `match`es.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ReflectKind {
     Component(Entity, TypeId),
     Resource(TypeId),
+    /// A component on the single entity with a marker component, given by the `TypeId`s of the marker and the
+    /// component. The entity is looked up every time the target is used, so the target does not go stale when the
+    /// entity is despawned and spawned again.
+    ComponentByMarker(TypeId, TypeId),
+}
+
+/// The kind of a [`ReflectTarget`] after looking up the entity of a `ReflectKind::ComponentByMarker` target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResolvedReflectKind {
+    Component(Entity, TypeId),
+    Resource(TypeId),
 }
 
 /// Type describing the path to a field on a target that can be operated on via reflection.
-#[derive(Debug, Clone)]
+///
+/// Targets are keyed purely by `TypeId`, so to target one of two structurally identical resources, wrap them in
+/// distinct types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ReflectTarget {
     pub kind: ReflectKind,
     pub field_path: String,
+    /// Whether `read_value` and `set_value` follow wrapper types with `ReflectFieldAccessor` type data until they reach
+    /// a value of the requested type. See `with_auto_deref`.
+    auto_deref: bool,
 }
 
 impl ReflectTarget {
-    pub fn new_resource<T: Resource + Reflect>(field_path: impl Into<String>) -> Self {
+    /// Returns a target for a field on the resource or component described by `kind`.
+    pub fn new(kind: ReflectKind, field_path: impl Into<String>) -> Self {
         Self {
-            kind: ReflectKind::Resource(TypeId::of::<T>()),
+            kind,
             field_path: field_path.into(),
+            auto_deref: false,
         }
     }
 
+    pub fn new_resource<T: Resource + Reflect>(field_path: impl Into<String>) -> Self {
+        Self::new(ReflectKind::Resource(TypeId::of::<T>()), field_path)
+    }
+
     pub fn new_component<T: Component + Reflect>(
         entity: Entity,
         field_path: impl Into<String>,
     ) -> Self {
-        Self {
-            kind: ReflectKind::Component(entity, TypeId::of::<T>()),
-            field_path: field_path.into(),
+        Self::new(
+            ReflectKind::Component(entity, TypeId::of::<T>()),
+            field_path,
+        )
+    }
+
+    /// Returns a target for a field on component `C`, on the single entity with the marker component `M`. See
+    /// `ReflectKind::ComponentByMarker`.
+    pub fn new_component_by_marker<C: Component + Reflect, M: Component>(
+        field_path: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            ReflectKind::ComponentByMarker(TypeId::of::<M>(), TypeId::of::<C>()),
+            field_path,
+        )
+    }
+
+    /// Returns this target with `auto_deref` enabled, so `read_value` and `set_value` read and set the inner value of
+    /// a wrapper field with `ReflectFieldAccessor` type data, e.g. `read_value::<Inner>` on a `Wrapper(Inner)` field.
+    ///
+    /// Only `read_value` and `set_value` follow the wrapper, as they are the only accessors given the type to stop at.
+    /// Every other accessor, including the serialized ones, `update_value`, and the readers, caches, widget states and
+    /// watches built on a target, operates on the wrapper field itself.
+    pub fn with_auto_deref(mut self) -> Self {
+        self.auto_deref = true;
+        self
+    }
+
+    /// Whether `auto_deref` is enabled. See `with_auto_deref`.
+    pub fn auto_deref(&self) -> bool {
+        self.auto_deref
+    }
+
+    /// Same as `new_resource`, but checks that `T`'s type path is not shared by another type registered in the world,
+    /// which would make looking the type up by its path ambiguous.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if more than one registered type has `T`'s type path.
+    pub fn new_resource_checked<T: Resource + Reflect + TypePath>(
+        world: &World,
+        field_path: impl Into<String>,
+    ) -> Self {
+        debug_assert_unique_type_path::<T>(world);
+        Self::new_resource::<T>(field_path)
+    }
+
+    /// Same as `new_component`, but checks that `T`'s type path is not shared by another type registered in the world.
+    /// See `new_resource_checked`.
+    pub fn new_component_checked<T: Component + Reflect + TypePath>(
+        world: &World,
+        entity: Entity,
+        field_path: impl Into<String>,
+    ) -> Self {
+        debug_assert_unique_type_path::<T>(world);
+        Self::new_component::<T>(entity, field_path)
+    }
+
+    /// Returns a copy of this target pointing at the same component field on a different entity.
+    ///
+    /// Resource targets and targets found by a marker are not tied to an entity, so they are returned unchanged.
+    pub fn retarget_entity(&self, new_entity: Entity) -> ReflectTarget {
+        match self.kind {
+            ReflectKind::Component(_, type_id) => Self {
+                kind: ReflectKind::Component(new_entity, type_id),
+                ..self.clone()
+            },
+            ReflectKind::Resource(_) | ReflectKind::ComponentByMarker(..) => self.clone(),
         }
     }
+
+    /// Returns the segments of the field path, split on `.`, e.g. `["theme", "0"]` for `"theme.0"`. The root path `""`
+    /// has no segments.
+    pub fn path_segments(&self) -> Vec<&str> {
+        self.field_path
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+
+    /// Returns a command that starts watching this target for changes.
+    ///
+    /// Requires [`ReflectWatchPlugin<T>`] to be added to the app, which sends a [`ReflectFieldChanged<T>`] event
+    /// whenever the value changes.
+    pub fn watch<T: Reflect + Clone>(&self) -> ReflectWatch<T> {
+        ReflectWatch::new(self.clone())
+    }
+}
+
+/// Panics in debug builds if more than one type registered in the world has `T`'s type path, e.g. because of a
+/// `#[type_path]` attribute copied between types.
+#[cfg(debug_assertions)]
+fn debug_assert_unique_type_path<T: TypePath>(world: &World) {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let count = type_registry
+        .iter()
+        .filter(|registration| registration.type_info().type_path() == T::type_path())
+        .count();
+    assert!(
+        count <= 1,
+        "{count} registered types have the type path `{}`",
+        T::type_path()
+    );
 }
 
+#[cfg(not(debug_assertions))]
+fn debug_assert_unique_type_path<T: TypePath>(_world: &World) {}
+
 impl ReflectTarget {
-    pub fn read_value<T: Reflect + Clone>(&self, world: &mut World) -> Result<T, ReflectError> {
-        match self.kind {
+    /// Returns whether the entity and component, or the resource, this target points to exist.
+    ///
+    /// Does not check whether the field path resolves.
+    pub fn owner_exists(&self, world: &World) -> bool {
+        match self.resolve_kind(world) {
+            Ok(ResolvedReflectKind::Component(entity, type_id)) => world
+                .get_entity(entity)
+                .is_some_and(|entity_ref| entity_ref.contains_type_id(type_id)),
+            Ok(ResolvedReflectKind::Resource(type_id)) => world
+                .components()
+                .get_resource_id(type_id)
+                .is_some_and(|component_id| world.get_resource_by_id(component_id).is_some()),
+            Err(_) => false,
+        }
+    }
+
+    /// Resolves the kind of this target, looking up the entity with the marker component of a
+    /// `ReflectKind::ComponentByMarker` target.
+    ///
+    /// Returns `ReflectError::EntityNotFound` if no entity has the marker, or `ReflectError::AmbiguousEntity` if more
+    /// than one does.
+    pub(crate) fn resolve_kind(&self, world: &World) -> Result<ResolvedReflectKind, ReflectError> {
+        let (marker_type_id, type_id) = match self.kind {
             ReflectKind::Component(entity, type_id) => {
+                return Ok(ResolvedReflectKind::Component(entity, type_id))
+            }
+            ReflectKind::Resource(type_id) => return Ok(ResolvedReflectKind::Resource(type_id)),
+            ReflectKind::ComponentByMarker(marker_type_id, type_id) => (marker_type_id, type_id),
+        };
+
+        let marker_id = world
+            .components()
+            .get_id(marker_type_id)
+            .ok_or(ReflectError::EntityNotFound)?;
+        let mut entities = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(marker_id))
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()));
+        let entity = entities.next().ok_or(ReflectError::EntityNotFound)?;
+        if entities.next().is_some() {
+            return Err(ReflectError::AmbiguousEntity);
+        }
+        Ok(ResolvedReflectKind::Component(entity, type_id))
+    }
+
+    /// Runs a closure with readonly access to the reflected value of the field.
+    pub(crate) fn with_field<R>(
+        &self,
+        world: &World,
+        read_fn: impl FnOnce(&dyn Reflect) -> R,
+    ) -> Result<R, ReflectError> {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                let type_registry = world.resource::<AppTypeRegistry>().read();
+                let entity_ref = world
+                    .get_entity(entity)
+                    .ok_or(ReflectError::EntityNotFound)?;
+                with_component_reflect_field(
+                    &entity_ref,
+                    &type_registry,
+                    type_id,
+                    &self.field_path,
+                    read_fn,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => {
+                with_resource_reflect_field(world, type_id, &self.field_path, read_fn)
+            }
+        }
+    }
+
+    /// Runs a closure with mutable access to the reflected value of the field.
+    pub(crate) fn with_field_mut<R>(
+        &self,
+        world: &mut World,
+        update_fn: impl FnOnce(&mut dyn Reflect) -> R,
+    ) -> Result<R, ReflectError> {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                with_reflect_component_field_mut_world(
+                    world,
+                    type_id,
+                    entity,
+                    &self.field_path,
+                    update_fn,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => {
+                with_resource_reflect_field_mut(world, type_id, &self.field_path, update_fn)
+            }
+        }
+    }
+
+    pub fn read_value<T: Reflect + Clone>(&self, world: &mut World) -> Result<T, ReflectError> {
+        if self.auto_deref {
+            let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = app_type_registry.read();
+            return self.with_field(world, |field| {
+                reflect_auto_deref::<T>(&type_registry, field)
+                    .cloned()
+                    .ok_or(ReflectError::InvalidDowncast)
+            })?;
+        }
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
                 reflect_component_read_path_from_world(world, entity, type_id, &self.field_path)
             }
-            ReflectKind::Resource(type_id) => {
+            ResolvedReflectKind::Resource(type_id) => {
                 reflect_resource_read_path(world, type_id, &self.field_path)
             }
         }
     }
 
+    /// Reads the value of a numeric field as an `f64`, regardless of the field's numeric type.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a numeric primitive. See `reflect_number_as_f64` for
+    /// the supported types.
+    pub fn read_value_number(&self, world: &World) -> Result<f64, ReflectError> {
+        self.with_field(world, |field| {
+            reflect_number_as_f64(field).ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
     pub fn set_value<T: Reflect>(&self, world: &mut World, value: T) -> ReflectSetResult {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => {
+        if self.auto_deref {
+            let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+            return self.with_field_mut(world, |field| {
+                let type_registry = app_type_registry.read();
+                let field = reflect_auto_deref_mut::<T>(&type_registry, field)
+                    .ok_or(ReflectError::InvalidDowncast)?;
+                reflect_set_field(field, Box::new(value))
+            })?;
+        }
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
                 reflect_component_set_path(world, type_id, entity, &self.field_path, value)
             }
-            ReflectKind::Resource(type_id) => {
+            ResolvedReflectKind::Resource(type_id) => {
                 reflect_resource_set_path(world, type_id, &self.field_path, value)
             }
         }
     }
 
+    /// Sets the value of the field to a new value computed from the current one, e.g. to increment a number, in a
+    /// single reflection call.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `T`.
+    pub fn update_value<T: Reflect + Clone>(
+        &self,
+        world: &mut World,
+        update_fn: impl FnOnce(T) -> T,
+    ) -> ReflectSetResult {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_update_path(world, type_id, entity, &self.field_path, update_fn)
+            }
+            ResolvedReflectKind::Resource(type_id) => {
+                reflect_resource_update_path(world, type_id, &self.field_path, update_fn)
+            }
+        }
+    }
+
+    /// Sets the value of the field, registering the target's component or resource type `T` in the `AppTypeRegistry`
+    /// first if it is missing.
+    ///
+    /// `T` must be the type this target points to. Registering a type takes a write lock on the `AppTypeRegistry`, so
+    /// this must not be called while a read lock on the registry is held.
+    pub fn set_value_registering<T: GetTypeRegistration, V: Reflect>(
+        &self,
+        world: &mut World,
+        value: V,
+    ) -> ReflectSetResult {
+        let app_type_registry = world.resource::<AppTypeRegistry>();
+        if !app_type_registry.read().contains(TypeId::of::<T>()) {
+            app_type_registry.write().register::<T>();
+        }
+        self.set_value(world, value)
+    }
+
+    /// Sets the value of the field, returning the serialized values before and after the operation.
+    ///
+    /// This reads and serializes the field twice, so prefer `set_value` when you don't need the details.
+    pub fn set_value_verbose<T: Reflect>(
+        &self,
+        world: &mut World,
+        value: T,
+    ) -> Result<ReflectSetSuccessVerbose, ReflectError> {
+        let old = self.read_value_serialized(world)?;
+        let result = self.set_value(world, value)?;
+        let new = self.read_value_serialized(world)?;
+        Ok(ReflectSetSuccessVerbose {
+            changed: result == ReflectSetSuccess::Changed,
+            old,
+            new,
+        })
+    }
+
+    /// Sets the value of a numeric field from an `f64`, narrowing it to the field's numeric type.
+    ///
+    /// Integer fields truncate towards zero and saturate at their minimum and maximum values. See
+    /// `reflect_number_from_f64` for the supported types and narrowing rules.
+    ///
+    /// Returns `ReflectError::UnsupportedNumericType` if the field is not a supported numeric type. Use
+    /// `set_value_coerced` to reject values that do not fit instead.
+    pub fn set_value_number(&self, world: &mut World, value: f64) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            let value = field
+                .get_represented_type_info()
+                .and_then(|type_info| reflect_number_from_f64(type_info.type_id(), value))
+                .ok_or(ReflectError::UnsupportedNumericType)?;
+            reflect_set_field(field, value)
+        })?
+    }
+
+    /// Sets the value of the field, converting numeric primitives to the field's numeric type if they differ, e.g. an
+    /// `i32` value on an `i64` field.
+    ///
+    /// Returns `ReflectError::NumericOutOfRange` if the value does not fit the field's type. See
+    /// `reflect_number_coerce` for the conversion rules. Values of any other type are set as-is.
+    pub fn set_value_coerced<T: Reflect>(&self, world: &mut World, value: T) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            let value: Box<dyn Reflect> = match field.get_represented_type_info() {
+                Some(type_info)
+                    if type_info.type_id() != TypeId::of::<T>()
+                        && is_reflect_number_type(type_info.type_id()) =>
+                {
+                    reflect_number_coerce(&value, type_info.type_id())?
+                }
+                _ => Box::new(value),
+            };
+            reflect_set_field(field, value)
+        })?
+    }
+
+    /// Reads the `RangeInclusive` custom attribute declared on the field, e.g. `#[reflect(@0.0..=1.0_f32)]`, as a range
+    /// of `f64`. See `reflect_range_attribute`.
+    ///
+    /// Returns `None` if the field has no range attribute, or the path does not end in a struct field or tuple struct
+    /// index.
+    pub fn read_range_attribute(
+        &self,
+        world: &World,
+    ) -> Result<Option<RangeInclusive<f64>>, ReflectError> {
+        let mut parsed_path = ParsedPath::parse(&self.field_path)
+            .map_err(|err| ReflectError::ReflectPath(err.to_string()))?;
+        let Some(last) = parsed_path.0.pop() else {
+            return Ok(None);
+        };
+        let owner = ReflectTarget::new(self.kind, "");
+        owner.with_field(world, |root| {
+            let parent = root
+                .reflect_path(&parsed_path)
+                .map_err(|err| reflect_path_error(root, &self.field_path, err))?;
+            let attributes = match (parent.get_represented_type_info(), &last.access) {
+                (Some(TypeInfo::Struct(struct_info)), Access::Field(name)) => struct_info
+                    .field(name)
+                    .map(|field| field.custom_attributes()),
+                (Some(TypeInfo::Struct(struct_info)), Access::FieldIndex(index)) => struct_info
+                    .field_at(*index)
+                    .map(|field| field.custom_attributes()),
+                (Some(TypeInfo::TupleStruct(tuple_struct_info)), Access::TupleIndex(index)) => {
+                    tuple_struct_info
+                        .field_at(*index)
+                        .map(|field| field.custom_attributes())
+                }
+                _ => None,
+            };
+            Ok(attributes.and_then(reflect_range_attribute))
+        })?
+    }
+
+    /// Sets the value of a numeric field, rejecting values outside of the range declared by its range attribute. See
+    /// `read_range_attribute`. Values are set as-is if the field has no range attribute.
+    ///
+    /// Returns `ReflectError::NumericOutOfRange` if the value is outside of the range, or
+    /// `ReflectError::UnsupportedNumericType` if the field has a range but the value is not a numeric primitive.
+    pub fn set_value_checked<T: Reflect>(&self, world: &mut World, value: T) -> ReflectSetResult {
+        if let Some(range) = self.read_range_attribute(world)? {
+            let number =
+                reflect_number_as_f64(&value).ok_or(ReflectError::UnsupportedNumericType)?;
+            if !range.contains(&number) {
+                return Err(ReflectError::NumericOutOfRange);
+            }
+        }
+        self.set_value(world, value)
+    }
+
+    /// Sets the value of a `String` field.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `String`.
+    pub fn set_string(&self, world: &mut World, value: impl Into<String>) -> ReflectSetResult {
+        let value = value.into();
+        self.with_field_mut(world, |field| {
+            if !field.is::<String>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(value))
+        })?
+    }
+
+    /// Reads an `Option` field, returning `None` for the `None` variant, or the inner value of the `Some` variant built
+    /// with `FromReflect`.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not an `Option`, or the inner value is not a `T`.
+    pub fn read_optional<T: FromReflect>(&self, world: &World) -> Result<Option<T>, ReflectError> {
+        self.with_field(world, |field| {
+            let ReflectRef::Enum(dyn_enum) = field.reflect_ref() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+            let is_option = field.get_represented_type_info().is_some_and(|type_info| {
+                let type_path_table = type_info.type_path_table();
+                type_path_table.module_path() == Some("core::option")
+                    && type_path_table.ident() == Some("Option")
+            });
+            if !is_option {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            match dyn_enum.field_at(0) {
+                Some(value) => T::from_reflect(value)
+                    .map(Some)
+                    .ok_or(ReflectError::InvalidDowncast),
+                None => Ok(None),
+            }
+        })?
+    }
+
+    /// Reads a `Vec2` field as an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec2`.
+    pub fn read_vec2(&self, world: &World) -> Result<[f32; 2], ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<Vec2>()
+                .map(|value| value.to_array())
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a `Vec2` field from an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec2`.
+    pub fn set_vec2(&self, world: &mut World, value: [f32; 2]) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            if !field.is::<Vec2>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(Vec2::from_array(value)))
+        })?
+    }
+
+    /// Reads a `Vec3` field as an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec3`.
+    pub fn read_vec3(&self, world: &World) -> Result<[f32; 3], ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<Vec3>()
+                .map(|value| value.to_array())
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a `Vec3` field from an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec3`.
+    pub fn set_vec3(&self, world: &mut World, value: [f32; 3]) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            if !field.is::<Vec3>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(Vec3::from_array(value)))
+        })?
+    }
+
+    /// Reads a `Vec4` field as an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec4`.
+    pub fn read_vec4(&self, world: &World) -> Result<[f32; 4], ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<Vec4>()
+                .map(|value| value.to_array())
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a `Vec4` field from an array of its components.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Vec4`.
+    pub fn set_vec4(&self, world: &mut World, value: [f32; 4]) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            if !field.is::<Vec4>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(Vec4::from_array(value)))
+        })?
+    }
+
+    /// Reads a `Color` field as a hex string in sRGB, e.g. `"#FF8800"`, or `"#FF880080"` if it is not fully opaque.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Color`.
+    pub fn read_color_hex(&self, world: &World) -> Result<String, ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<Color>()
+                .map(|color| color.to_srgba().to_hex())
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Reads a string-like field as an owned `String`. Supports `String`, `Cow<'static, str>` and `&'static str`
+    /// fields.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not one of the supported string types.
+    pub fn read_string(&self, world: &World) -> Result<String, ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| {
+                    field
+                        .downcast_ref::<Cow<'static, str>>()
+                        .map(|value| value.to_string())
+                })
+                .or_else(|| {
+                    field
+                        .downcast_ref::<&'static str>()
+                        .map(|value| value.to_string())
+                })
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a `Color` field from a hex string, e.g. `"#FF8800"` or `"FF880080"`. See `Srgba::hex` for the accepted
+    /// formats.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Color`, or `ReflectError::Deserialize` if the
+    /// string is not a valid hex color.
+    pub fn set_color_hex(&self, world: &mut World, hex: impl AsRef<str>) -> ReflectSetResult {
+        let color = Srgba::hex(hex)
+            .map(Color::from)
+            .map_err(|err| ReflectError::Deserialize(err.to_string()))?;
+        self.with_field_mut(world, |field| {
+            if !field.is::<Color>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(color))
+        })?
+    }
+
+    /// Reads a `Duration` field, or the duration of a `Timer` field, in seconds.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Duration` or a `Timer`.
+    pub fn read_duration_secs(&self, world: &World) -> Result<f32, ReflectError> {
+        self.with_field(world, |field| {
+            field
+                .downcast_ref::<Duration>()
+                .copied()
+                .or_else(|| field.downcast_ref::<Timer>().map(Timer::duration))
+                .map(|duration| duration.as_secs_f32())
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a `Duration` field, or the duration of a `Timer` field, from seconds. The elapsed time of a `Timer` is kept.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not a `Duration` or a `Timer`, or
+    /// `ReflectError::NumericOutOfRange` if `secs` is negative, not finite, or overflows a `Duration`.
+    pub fn set_duration_secs(&self, world: &mut World, secs: f32) -> ReflectSetResult {
+        let duration =
+            Duration::try_from_secs_f32(secs).map_err(|_| ReflectError::NumericOutOfRange)?;
+        self.with_field_mut(world, |field| {
+            if let Some(timer) = field.downcast_mut::<Timer>() {
+                if timer.duration() == duration {
+                    return Ok(ReflectSetSuccess::NoChanges);
+                }
+                timer.set_duration(duration);
+                return Ok(ReflectSetSuccess::Changed);
+            }
+            if !field.is::<Duration>() {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            reflect_set_field(field, Box::new(duration))
+        })?
+    }
+
+    /// Reads all elements of a fixed-size array field, e.g. `[f32; 4]`.
+    ///
+    /// Returns `ReflectError::InvalidDowncast` if the field is not an array, or its elements are not of type `T`.
+    pub fn read_array<T: Reflect + Clone>(&self, world: &World) -> Result<Vec<T>, ReflectError> {
+        self.with_field(world, |field| {
+            let ReflectRef::Array(array) = field.reflect_ref() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+            array
+                .iter()
+                .map(|element| {
+                    element
+                        .downcast_ref::<T>()
+                        .cloned()
+                        .ok_or(ReflectError::InvalidDowncast)
+                })
+                .collect()
+        })?
+    }
+
+    /// Sets all elements of a fixed-size array field, e.g. `[f32; 4]`.
+    ///
+    /// Every element is validated before any are set. Returns `ReflectError::InvalidDowncast` if the field is not an
+    /// array or its elements are not of type `T`, or `ReflectError::ArrayLengthMismatch` if the number of values does
+    /// not match the length of the array.
+    pub fn set_array<T: Reflect>(&self, world: &mut World, values: Vec<T>) -> ReflectSetResult {
+        self.with_field_mut(world, |field| {
+            let ReflectMut::Array(array) = field.reflect_mut() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+            if array.len() != values.len() {
+                return Err(ReflectError::ArrayLengthMismatch {
+                    expected: array.len(),
+                    actual: values.len(),
+                });
+            }
+            if !array.iter().all(|element| element.is::<T>()) {
+                return Err(ReflectError::InvalidDowncast);
+            }
+
+            let mut result = ReflectSetSuccess::NoChanges;
+            for (index, value) in values.into_iter().enumerate() {
+                let element = array.get_mut(index).ok_or(ReflectError::SetValueFailed)?;
+                if reflect_set_field(element, Box::new(value))? == ReflectSetSuccess::Changed {
+                    result = ReflectSetSuccess::Changed;
+                }
+            }
+            Ok(result)
+        })?
+    }
+
     pub fn toggle_reflect_enum(
         &self,
         world: &mut World,
         direction: EnumDirection,
     ) -> ReflectSetResult {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => reflect_component_toggle_enum_variant(
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_toggle_enum_variant(
+                    world,
+                    type_id,
+                    entity,
+                    &self.field_path,
+                    direction,
+                    false,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => reflect_resource_toggle_enum_variant(
                 world,
                 type_id,
-                entity,
                 &self.field_path,
                 direction,
                 false,
             ),
-            ReflectKind::Resource(type_id) => reflect_resource_toggle_enum_variant(
+        }
+    }
+
+    /// Toggles the enum variant of the field, also returning the name of the resulting variant.
+    pub fn toggle_reflect_enum_named(
+        &self,
+        world: &mut World,
+        direction: EnumDirection,
+    ) -> Result<(ReflectSetSuccess, String), ReflectError> {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_toggle_enum_variant_named(
+                    world,
+                    type_id,
+                    entity,
+                    &self.field_path,
+                    direction,
+                    false,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => reflect_resource_toggle_enum_variant_named(
                 world,
                 type_id,
                 &self.field_path,
@@ -84,9 +760,86 @@ impl ReflectTarget {
         }
     }
 
+    /// Reads a named field of the active struct variant of the enum field, e.g. `speed` on `Mode::Custom { speed }`.
+    ///
+    /// Paths like `"mode.speed"` also reach struct variant fields, but this reports a clear error when the active
+    /// variant is a different one: `ReflectError::VariantFieldNotFound` if the active variant has no field named
+    /// `field_name`, or `ReflectError::InvalidDowncast` if the field is not an enum or the variant field is not a `T`.
+    pub fn read_variant_struct_field<T: Reflect + Clone>(
+        &self,
+        world: &World,
+        field_name: &str,
+    ) -> Result<T, ReflectError> {
+        self.with_field(world, |field| {
+            let ReflectRef::Enum(dyn_enum) = field.reflect_ref() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+            dyn_enum
+                .field(field_name)
+                .ok_or_else(|| ReflectError::VariantFieldNotFound {
+                    variant: dyn_enum.variant_name().to_string(),
+                    field: field_name.to_string(),
+                })?
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or(ReflectError::InvalidDowncast)
+        })?
+    }
+
+    /// Sets a field on a variant of the enum field, first switching the enum to that variant if it is not active.
+    ///
+    /// When switching variants, the new variant is constructed with the default values of its fields before setting
+    /// `sub_path` on it, and only applied to the field if that succeeds. `sub_path` is relative to the enum, e.g. `".0"`
+    /// for a tuple variant or `"color"` for a struct variant.
+    ///
+    /// Returns `ReflectError::VariantNotFound` if the enum has no variant named `variant_name`.
+    pub fn set_variant_field(
+        &self,
+        world: &mut World,
+        variant_name: &str,
+        sub_path: &str,
+        value: Box<dyn Reflect>,
+    ) -> ReflectSetResult {
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = app_type_registry.read();
+
+        self.with_field_mut(world, |field| {
+            let ReflectRef::Enum(dyn_enum) = field.reflect_ref() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+
+            // Set the sub-field directly if the variant is already active
+            if dyn_enum.variant_name() == variant_name {
+                return match field.reflect_path_mut(sub_path) {
+                    Ok(sub_field) => reflect_set_field(sub_field, value),
+                    Err(err) => Err(reflect_path_error(field.as_reflect(), sub_path, err)),
+                };
+            }
+
+            // Otherwise construct the default variant, set the sub-field on it, and apply it
+            let Some(TypeInfo::Enum(enum_info)) = dyn_enum.get_represented_type_info() else {
+                return Err(ReflectError::InvalidDowncast);
+            };
+            let variant_info = enum_info
+                .variant(variant_name)
+                .ok_or_else(|| ReflectError::VariantNotFound(variant_name.to_string()))?;
+            let mut new_variant = construct_default_enum_variant(variant_info, &type_registry)?;
+            match new_variant.reflect_path_mut(sub_path) {
+                Ok(sub_field) => {
+                    reflect_set_field(sub_field, value)?;
+                }
+                Err(err) => {
+                    return Err(reflect_path_error(new_variant.as_reflect(), sub_path, err));
+                }
+            }
+            field.apply(new_variant.as_reflect());
+            Ok(ReflectSetSuccess::Changed)
+        })?
+    }
+
     pub fn read_enum_variant_name(&self, world: &mut World) -> Result<String, ReflectError> {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
                 reflect_component_read_enum_variant_name_from_world(
                     world,
                     entity,
@@ -94,57 +847,1848 @@ impl ReflectTarget {
                     &self.field_path,
                 )
             }
-            ReflectKind::Resource(type_id) => {
+            ResolvedReflectKind::Resource(type_id) => {
                 reflect_resource_read_enum_variant_name(world, type_id, &self.field_path)
             }
         }
     }
 
     pub fn read_value_serialized(&self, world: &mut World) -> Result<String, ReflectError> {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
                 reflect_component_read_path_serialized(world, entity, type_id, &self.field_path)
             }
-            ReflectKind::Resource(type_id) => {
+            ResolvedReflectKind::Resource(type_id) => {
                 reflect_resource_read_path_serialized(world, type_id, &self.field_path)
             }
         }
     }
 
+    /// Reads the serialized value of the field without the `{"type::Path": value}` wrapper, e.g. `true` instead of
+    /// `{"bool":true}`, for display or for passing back to `set_value_serialized_typed`.
+    pub fn read_value_serialized_bare(&self, world: &World) -> Result<String, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        self.with_field(world, |field| {
+            serialize_reflect_value_typed(&type_registry, field)
+        })?
+    }
+
+    /// Reads the serialized value of the entry with a serialized key in a map field, e.g.
+    /// `{"alloc::string::String":"volume"}`.
+    ///
+    /// Returns `ReflectError::NotAMap` if the field is not a map, `ReflectError::Deserialize` if the key is not valid,
+    /// or `ReflectError::MapKeyNotFound` if the map has no entry for the key.
+    pub fn read_map_value_serialized(
+        &self,
+        world: &World,
+        key_serialized: &str,
+    ) -> Result<String, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let key = deserialize_reflect_value_with_registry(&type_registry, key_serialized)?;
+        self.with_field(world, |field| {
+            let ReflectRef::Map(map) = field.reflect_ref() else {
+                return Err(ReflectError::NotAMap);
+            };
+            let value = map
+                .get(key.as_reflect())
+                .ok_or_else(|| ReflectError::MapKeyNotFound(key_serialized.to_string()))?;
+            serialize_reflect_value(&type_registry, value)
+        })?
+    }
+
+    /// Sets the entry with a serialized key in a map field from a serialized value, inserting it if the map has no
+    /// entry for the key.
+    ///
+    /// Returns `ReflectError::NotAMap` if the field is not a map, `ReflectError::Deserialize` if the key or value is not
+    /// valid, or `ReflectError::InvalidDowncast` if they are not the map's key and value types.
+    pub fn set_map_value_serialized(
+        &self,
+        world: &mut World,
+        key_serialized: &str,
+        value_serialized: &str,
+    ) -> ReflectSetResult {
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = app_type_registry.read();
+        let key = deserialize_reflect_value_with_registry(&type_registry, key_serialized)?;
+        let value = deserialize_reflect_value_with_registry(&type_registry, value_serialized)?;
+        let represented_type_id =
+            |value: &dyn Reflect| value.get_represented_type_info().map(|info| info.type_id());
+
+        self.with_field_mut(world, |field| {
+            let Some(TypeInfo::Map(map_info)) = field.get_represented_type_info() else {
+                return Err(ReflectError::NotAMap);
+            };
+            // NOTE: Inserting a key or value of the wrong type panics, so check them first
+            if represented_type_id(key.as_reflect()) != Some(map_info.key_type_id())
+                || represented_type_id(value.as_reflect()) != Some(map_info.value_type_id())
+            {
+                return Err(ReflectError::InvalidDowncast);
+            }
+            let ReflectMut::Map(map) = field.reflect_mut() else {
+                return Err(ReflectError::NotAMap);
+            };
+            match map.get_mut(key.as_reflect()) {
+                Some(entry) => reflect_set_field(entry, value),
+                None => {
+                    map.insert_boxed(key, value);
+                    Ok(ReflectSetSuccess::Changed)
+                }
+            }
+        })?
+    }
+
+    /// Reads the value of the field in a compact binary encoding, e.g. for network replication. Requires the `bincode`
+    /// feature.
+    #[cfg(feature = "bincode")]
+    pub fn read_value_bytes(&self, world: &World) -> Result<Vec<u8>, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        self.with_field(world, |field| {
+            serialize_reflect_value_bytes(&type_registry, field)
+        })?
+    }
+
+    /// Sets the value of the field from bytes produced by `read_value_bytes`. Requires the `bincode` feature.
+    #[cfg(feature = "bincode")]
+    pub fn set_value_bytes(&self, world: &mut World, bytes: &[u8]) -> ReflectSetResult {
+        let value = {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            deserialize_reflect_value_bytes(&type_registry, bytes)?
+        };
+        self.with_field_mut(world, |field| reflect_set_field(field, value))?
+    }
+
+    /// Checks that a serialized value would de-serialize and match the type of the field, without setting it.
+    ///
+    /// Returns `ReflectError::Deserialize` if the value is malformed, or `ReflectError::InvalidDowncast` if it is a
+    /// different type to the field.
+    pub fn validate_serialized(&self, world: &World, value: &str) -> Result<(), ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let value = deserialize_reflect_value_with_registry(&type_registry, value)?;
+        let value_type_path = value
+            .get_represented_type_info()
+            .map(|type_info| type_info.type_path());
+
+        let field_type_path = self.with_field(world, |field| {
+            field
+                .get_represented_type_info()
+                .map(|type_info| type_info.type_path())
+        })?;
+        if value_type_path.is_none() || value_type_path != field_type_path {
+            return Err(ReflectError::InvalidDowncast);
+        }
+        Ok(())
+    }
+
+    /// Sets the field from a bare RON value, without the `{"type::Path": value}` wrapper, e.g. `"true"` instead of
+    /// `"{\"bool\":true}"`. The value is de-serialized as the field's type.
+    ///
+    /// Returns `ReflectError::TypeRegistrationNotFound` if the field's type is not registered, or
+    /// `ReflectError::Deserialize` if the value is not valid for the field's type.
+    pub fn set_value_serialized_typed(
+        &self,
+        world: &mut World,
+        bare_value: &str,
+    ) -> ReflectSetResult {
+        let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_id = self
+            .with_field(world, |field| {
+                field
+                    .get_represented_type_info()
+                    .map(|type_info| type_info.type_id())
+            })?
+            .ok_or(ReflectError::TypeRegistrationNotFound)?;
+        let value =
+            deserialize_reflect_value_typed(&app_type_registry.read(), type_id, bare_value)?;
+
+        self.with_field_mut(world, |field| {
+            reflect_set_field_comparing_serialized(&app_type_registry.read(), field, value)
+        })?
+    }
+
+    /// Reads every immediate field of a struct target as `(name, serialized_value)` pairs, in declaration order.
+    ///
+    /// Returns `ReflectError::NotAStruct` if the target is not a struct.
+    pub fn read_fields_serialized(
+        &self,
+        world: &World,
+    ) -> Result<Vec<(String, String)>, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        self.with_field(world, |value| {
+            let ReflectRef::Struct(value) = value.reflect_ref() else {
+                return Err(ReflectError::NotAStruct);
+            };
+            value
+                .iter_fields()
+                .enumerate()
+                .map(|(index, field)| {
+                    let name = value.name_at(index).unwrap_or_default().to_string();
+                    Ok((name, serialize_reflect_value(&type_registry, field)?))
+                })
+                .collect()
+        })?
+    }
+
+    /// Calls `visit_fn` with the name and value of every immediate field of a struct target, in declaration order.
+    ///
+    /// Unlike `read_fields_serialized`, nothing is collected or serialized. Returns `ReflectError::NotAStruct` if the
+    /// target is not a struct.
+    pub fn for_each_field(
+        &self,
+        world: &World,
+        mut visit_fn: impl FnMut(&str, &dyn Reflect),
+    ) -> Result<(), ReflectError> {
+        self.with_field(world, |value| {
+            let ReflectRef::Struct(value) = value.reflect_ref() else {
+                return Err(ReflectError::NotAStruct);
+            };
+            for (index, field) in value.iter_fields().enumerate() {
+                visit_fn(value.name_at(index).unwrap_or_default(), field);
+            }
+            Ok(())
+        })?
+    }
+
     pub fn set_value_serialized(&self, world: &mut World, value: &str) -> ReflectSetResult {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => reflect_component_set_path_serialized(
-                world,
-                entity,
-                type_id,
-                &self.field_path,
-                value,
-            ),
-            ReflectKind::Resource(type_id) => {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_set_path_serialized(
+                    world,
+                    entity,
+                    type_id,
+                    &self.field_path,
+                    value,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => {
                 reflect_resource_set_path_serialized(world, type_id, &self.field_path, value)
             }
         }
     }
 
-    pub fn partial_eq_serialized(
+    /// Sets the serialized value of the field and logs the result, prefixed with `context`. See
+    /// [`ReflectTarget::set_value_serialized`].
+    ///
+    /// Uses the levels from the [`ReflectLogLevel`] resource if it exists, or its defaults otherwise.
+    pub fn set_value_serialized_logged(
         &self,
         world: &mut World,
-        serialized_value: &str,
-    ) -> Result<bool, ReflectError> {
-        match self.kind {
-            ReflectKind::Component(entity, type_id) => reflect_component_partial_eq_serialized(
-                world,
-                entity,
-                type_id,
-                &self.field_path,
-                serialized_value,
-            ),
-            ReflectKind::Resource(type_id) => reflect_resource_partial_eq_serialized(
-                world,
-                type_id,
-                &self.field_path,
-                serialized_value,
-            ),
+        value: &str,
+        context: &str,
+    ) -> ReflectSetResult {
+        let result = self.set_value_serialized(world, value);
+        world
+            .get_resource::<ReflectLogLevel>()
+            .copied()
+            .unwrap_or_default()
+            .log_set_result(context, &result);
+        result
+    }
+
+    /// Sets the serialized value of the field, then runs `validate` against the whole resource or component and
+    /// restores the previous value if it returns `false`, e.g. to enforce invariants like "min must be <= max".
+    ///
+    /// Returns `ReflectError::ValidationFailed` if the value was rolled back, or `ReflectError::RollbackFailed` if
+    /// restoring the previous value failed, in which case the rejected value is still in the world.
+    pub fn set_value_serialized_validated(
+        &self,
+        world: &mut World,
+        value: &str,
+        validate: impl Fn(&dyn Reflect) -> bool,
+    ) -> ReflectSetResult {
+        let snapshot = self.read_value_serialized(world)?;
+        let result = self.set_value_serialized(world, value)?;
+
+        let owner = ReflectTarget::new(self.kind, "");
+        if !owner.with_field(world, validate)? {
+            self.set_value_serialized(world, &snapshot)
+                .map_err(|err| ReflectError::RollbackFailed(Box::new(err)))?;
+            return Err(ReflectError::ValidationFailed);
         }
+        Ok(result)
+    }
+
+    pub fn set_value_serialized_with_format(
+        &self,
+        world: &mut World,
+        value: &str,
+        format: SerializationFormat,
+    ) -> ReflectSetResult {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_set_path_serialized_with_format(
+                    world,
+                    entity,
+                    type_id,
+                    &self.field_path,
+                    value,
+                    format,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => {
+                reflect_resource_set_path_serialized_with_format(
+                    world,
+                    type_id,
+                    &self.field_path,
+                    value,
+                    format,
+                )
+            }
+        }
+    }
+
+    /// Sets the value of the field from a serialized value, returning the serialized values before and after the
+    /// operation.
+    pub fn set_value_serialized_verbose(
+        &self,
+        world: &mut World,
+        value: &str,
+    ) -> Result<ReflectSetSuccessVerbose, ReflectError> {
+        let old = self.read_value_serialized(world)?;
+        let result = self.set_value_serialized(world, value)?;
+        let new = self.read_value_serialized(world)?;
+        Ok(ReflectSetSuccessVerbose {
+            changed: result == ReflectSetSuccess::Changed,
+            old,
+            new,
+        })
+    }
+
+    /// Sets the value of the field from a serialized value, also returning whether the field already matched the value.
+    ///
+    /// This saves calling `partial_eq_serialized` before setting the value, e.g. to update the selected state of a
+    /// button. Values are compared the same way as [`ReflectTarget::set_value_serialized`].
+    pub fn set_if_different_serialized(
+        &self,
+        world: &mut World,
+        value: &str,
+    ) -> Result<ReflectSetIfDifferentSuccess, ReflectError> {
+        let success = self.set_value_serialized(world, value)?;
+        Ok(ReflectSetIfDifferentSuccess { success })
+    }
+
+    pub fn partial_eq_serialized(
+        &self,
+        world: &mut World,
+        serialized_value: &str,
+    ) -> Result<bool, ReflectError> {
+        match self.resolve_kind(world)? {
+            ResolvedReflectKind::Component(entity, type_id) => {
+                reflect_component_partial_eq_serialized(
+                    world,
+                    entity,
+                    type_id,
+                    &self.field_path,
+                    serialized_value,
+                )
+            }
+            ResolvedReflectKind::Resource(type_id) => reflect_resource_partial_eq_serialized(
+                world,
+                type_id,
+                &self.field_path,
+                serialized_value,
+            ),
+        }
+    }
+
+    /// Whether the field differs from a serialized value, e.g. to show a "modified" marker next to a saved setting. The
+    /// negation of `partial_eq_serialized`.
+    pub fn differs_from_serialized(
+        &self,
+        world: &mut World,
+        saved: &str,
+    ) -> Result<bool, ReflectError> {
+        self.partial_eq_serialized(world, saved).map(|is_eq| !is_eq)
+    }
+
+    /// Whether the field differs from the default value of its type.
+    ///
+    /// Returns `ReflectError::NoDefaultValue` if the field's type does not reflect `Default`.
+    pub fn differs_from_default(&self, world: &World) -> Result<bool, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        self.with_field(world, |field| {
+            let type_id = field
+                .get_represented_type_info()
+                .map(|type_info| type_info.type_id())
+                .ok_or(ReflectError::TypeRegistrationNotFound)?;
+            let default_value = reflect_default_value(&type_registry, type_id)?;
+            field
+                .reflect_partial_eq(default_value.as_reflect())
+                .map(|is_eq| !is_eq)
+                .ok_or(ReflectError::PartialEq)
+        })?
+    }
+
+    /// Returns the reflect kind of the field, e.g. to decide which widget to display for it.
+    pub fn field_reflect_kind(&self, world: &World) -> Result<FieldKind, ReflectError> {
+        self.with_field(world, |field| match field.reflect_ref() {
+            ReflectRef::Struct(_) => FieldKind::Struct,
+            ReflectRef::TupleStruct(_) => FieldKind::TupleStruct,
+            ReflectRef::Tuple(_) => FieldKind::Tuple,
+            ReflectRef::List(_) => FieldKind::List,
+            ReflectRef::Array(_) => FieldKind::Array,
+            ReflectRef::Map(_) => FieldKind::Map,
+            ReflectRef::Enum(_) => FieldKind::Enum,
+            ReflectRef::Value(value) => FieldKind::Value(value.as_any().type_id()),
+        })
+    }
+
+    /// Whether the field is an enum. See [`FieldKind::is_enum`].
+    pub fn is_enum(&self, world: &World) -> Result<bool, ReflectError> {
+        self.field_reflect_kind(world).map(|kind| kind.is_enum())
+    }
+
+    /// Whether the field is a numeric primitive. See [`FieldKind::is_numeric`].
+    pub fn is_numeric(&self, world: &World) -> Result<bool, ReflectError> {
+        self.field_reflect_kind(world).map(|kind| kind.is_numeric())
+    }
+
+    /// Whether the field is a `bool`. See [`FieldKind::is_bool`].
+    pub fn is_bool(&self, world: &World) -> Result<bool, ReflectError> {
+        self.field_reflect_kind(world).map(|kind| kind.is_bool())
+    }
+
+    /// Walks the field path one segment at a time, reporting the deepest segment that resolved and the one that failed.
+    ///
+    /// Returns `Ok(())` if the whole path resolves. Useful for debugging paths that return `ReflectError::ReflectPath`.
+    pub fn diagnose_path(&self, world: &World) -> Result<(), ReflectPathDiagnostic> {
+        let root_target = ReflectTarget::new(self.kind, "");
+        root_target
+            .with_field(world, |root| diagnose_reflect_path(root, &self.field_path))
+            .map_err(|err| ReflectPathDiagnostic {
+                resolved: String::new(),
+                failed_segment: String::new(),
+                type_path: String::new(),
+                error: err.to_string(),
+            })?
+    }
+}
+
+/// A [`ReflectError`] along with the [`ReflectTarget`] that produced it.
+///
+/// Returned by the `*_with_context` methods on [`ReflectTarget`], or created with [`ReflectError::context`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetedReflectError {
+    pub target: ReflectTarget,
+    /// The type path of the component or resource, or `None` if the type is not registered.
+    pub type_path: Option<String>,
+    pub error: ReflectError,
+}
+
+impl fmt::Display for TargetedReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_path = self.type_path.as_deref().unwrap_or("<unregistered type>");
+        match self.target.kind {
+            ReflectKind::Component(entity, _) => write!(
+                f,
+                "{type_path} \"{}\" on entity {entity}: {}",
+                self.target.field_path, self.error
+            ),
+            ReflectKind::Resource(_) => write!(
+                f,
+                "{type_path} \"{}\": {}",
+                self.target.field_path, self.error
+            ),
+            ReflectKind::ComponentByMarker(..) => write!(
+                f,
+                "{type_path} \"{}\" on the entity with a marker: {}",
+                self.target.field_path, self.error
+            ),
+        }
+    }
+}
+
+impl ReflectError {
+    /// Attaches the target that produced this error, looking up its type path in the world's type registry.
+    pub fn context(self, world: &World, target: &ReflectTarget) -> TargetedReflectError {
+        TargetedReflectError {
+            target: target.clone(),
+            type_path: target.owner_type_path(world),
+            error: self,
+        }
+    }
+}
+
+impl ReflectTarget {
+    /// Returns the type path of the target's component or resource, or `None` if the type is not registered.
+    fn owner_type_path(&self, world: &World) -> Option<String> {
+        let type_id = match self.kind {
+            ReflectKind::Component(_, type_id)
+            | ReflectKind::Resource(type_id)
+            | ReflectKind::ComponentByMarker(_, type_id) => type_id,
+        };
+        world
+            .get_resource::<AppTypeRegistry>()
+            .and_then(|app_type_registry| {
+                app_type_registry
+                    .read()
+                    .get(type_id)
+                    .map(|registration| registration.type_info().type_path().to_string())
+            })
+    }
+
+    /// Returns a multi-line, human readable summary of the target and the current state of its field, e.g. for logging
+    /// errors.
+    ///
+    /// ```text
+    /// kind: resource
+    /// type: my_game::Settings
+    /// field path: "volume"
+    /// field type: u32
+    /// value: {"u32":5}
+    /// accessible: yes
+    /// ```
+    pub fn describe(&self, world: &mut World) -> String {
+        let kind = match self.kind {
+            ReflectKind::Component(entity, _) => format!("component on entity {entity}"),
+            ReflectKind::Resource(_) => "resource".to_string(),
+            ReflectKind::ComponentByMarker(..) => {
+                "component on the entity with a marker".to_string()
+            }
+        };
+        let type_path = self
+            .owner_type_path(world)
+            .unwrap_or_else(|| "<unregistered type>".to_string());
+        let field_type_path = self.with_field(world, |field| field.reflect_type_path().to_string());
+        let value = self.read_value_serialized(world);
+
+        let accessible = match &field_type_path {
+            Ok(_) => "yes".to_string(),
+            Err(err) => format!("no ({err})"),
+        };
+        let describe_result = |result: Result<String, ReflectError>| {
+            result.unwrap_or_else(|err| format!("<error: {err}>"))
+        };
+        format!(
+            "kind: {kind}\n\
+            type: {type_path}\n\
+            field path: \"{}\"\n\
+            field type: {}\n\
+            value: {}\n\
+            accessible: {accessible}",
+            self.field_path,
+            describe_result(field_type_path),
+            describe_result(value),
+        )
+    }
+}
+
+impl ReflectTarget {
+    /// Same as `read_value`, but errors include this target.
+    pub fn read_value_with_context<T: Reflect + Clone>(
+        &self,
+        world: &mut World,
+    ) -> Result<T, TargetedReflectError> {
+        self.read_value(world)
+            .map_err(|error| error.context(world, self))
+    }
+
+    /// Same as `set_value`, but errors include this target.
+    pub fn set_value_with_context<T: Reflect>(
+        &self,
+        world: &mut World,
+        value: T,
+    ) -> Result<ReflectSetSuccess, TargetedReflectError> {
+        self.set_value(world, value)
+            .map_err(|error| error.context(world, self))
+    }
+
+    /// Same as `set_value_serialized`, but errors include this target.
+    pub fn set_value_serialized_with_context(
+        &self,
+        world: &mut World,
+        value: &str,
+    ) -> Result<ReflectSetSuccess, TargetedReflectError> {
+        self.set_value_serialized(world, value)
+            .map_err(|error| error.context(world, self))
+    }
+
+    /// Same as `toggle_reflect_enum`, but errors include this target.
+    pub fn toggle_reflect_enum_with_context(
+        &self,
+        world: &mut World,
+        direction: EnumDirection,
+    ) -> Result<ReflectSetSuccess, TargetedReflectError> {
+        self.toggle_reflect_enum(world, direction)
+            .map_err(|error| error.context(world, self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::utils::HashMap;
+
+    use super::*;
+
+    #[derive(Reflect, Default, PartialEq, Eq, Debug)]
+    enum EnumA {
+        #[default]
+        A,
+        B,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+        mode: EnumA,
+        small: u8,
+        ratio: f32,
+        name: String,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+    }
+
+    #[derive(Reflect, Default, PartialEq, Eq, Debug)]
+    enum EnumB {
+        #[default]
+        None,
+        Custom(u32),
+    }
+
+    #[derive(Reflect, Default, PartialEq, Debug)]
+    enum EnumC {
+        #[default]
+        Off,
+        Custom {
+            speed: f32,
+        },
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceB {
+        value2: (u32, u32),
+        theme: EnumB,
+        motion: EnumC,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceC {
+        color: Color,
+        value: i32,
+        enabled: bool,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceD {
+        #[reflect(@0..=10_i32)]
+        level: i32,
+        unbounded: i32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    #[type_path = "bevy_reflect_utils::reflect_target::tests"]
+    #[type_name = "DuplicatePath"]
+    struct DuplicatePathA {
+        value: i32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    #[type_path = "bevy_reflect_utils::reflect_target::tests"]
+    #[type_name = "DuplicatePath"]
+    struct DuplicatePathB {
+        value: i32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct NonRegisteredResource {
+        value: i32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<ComponentA>();
+        type_registry.write().register::<EnumA>();
+        type_registry.write().register::<ResourceB>();
+        type_registry.write().register::<ResourceC>();
+        type_registry.write().register::<ResourceD>();
+
+        world
+    }
+
+    #[test]
+    fn set_value_verbose_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+
+        let result = target.set_value_verbose(&mut world, EnumA::B).unwrap();
+        assert_eq!(
+            result,
+            ReflectSetSuccessVerbose {
+                changed: true,
+                old: "{\"bevy_reflect_utils::reflect_target::tests::EnumA\":A}".to_string(),
+                new: "{\"bevy_reflect_utils::reflect_target::tests::EnumA\":B}".to_string(),
+            }
+        );
+        assert_eq!(world.resource::<ResourceA>().mode, EnumA::B);
+
+        // Test setting the same value again reports no changes
+        let result = target.set_value_verbose(&mut world, EnumA::B).unwrap();
+        assert!(!result.changed);
+        assert_eq!(result.old, result.new);
+    }
+
+    #[test]
+    fn read_value_number_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value: -5,
+            small: 200,
+            ratio: 0.5,
+            ..default()
+        });
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(target.read_value_number(&world), Ok(-5.));
+
+        let target = ReflectTarget::new_resource::<ResourceA>("small");
+        assert_eq!(target.read_value_number(&world), Ok(200.));
+
+        let target = ReflectTarget::new_resource::<ResourceA>("ratio");
+        assert_eq!(target.read_value_number(&world), Ok(0.5));
+
+        // Test the error when the field is not a number
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(
+            target.read_value_number(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn read_string_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceLabels {
+            label: Cow<'static, str>,
+            tag: &'static str,
+            count: i32,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceLabels>();
+        world.insert_resource(ResourceA {
+            name: "Player".to_string(),
+            ..default()
+        });
+        world.insert_resource(ResourceLabels {
+            label: Cow::Owned("Health".to_string()),
+            tag: "hud",
+            ..default()
+        });
+
+        // Test each supported string type is read as an owned `String`
+        let read = |target: ReflectTarget| target.read_string(&world);
+        assert_eq!(
+            read(ReflectTarget::new_resource::<ResourceA>("name")),
+            Ok("Player".to_string())
+        );
+        assert_eq!(
+            read(ReflectTarget::new_resource::<ResourceLabels>("label")),
+            Ok("Health".to_string())
+        );
+        assert_eq!(
+            read(ReflectTarget::new_resource::<ResourceLabels>("tag")),
+            Ok("hud".to_string())
+        );
+
+        // Test the error when the field is not a string
+        assert_eq!(
+            read(ReflectTarget::new_resource::<ResourceLabels>("count")),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn map_value_serialized_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceScores {
+            scores: HashMap<String, i32>,
+            best: i32,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceScores>();
+        world.insert_resource(ResourceScores {
+            scores: HashMap::from([("a".to_string(), 1)]),
+            ..default()
+        });
+        let target = ReflectTarget::new_resource::<ResourceScores>("scores");
+        let key_a = "{\"alloc::string::String\":\"a\"}";
+        let key_b = "{\"alloc::string::String\":\"b\"}";
+
+        // Test reading an entry by key
+        assert_eq!(
+            target.read_map_value_serialized(&world, key_a),
+            Ok("{\"i32\":1}".to_string())
+        );
+        assert_eq!(
+            target.read_map_value_serialized(&world, key_b),
+            Err(ReflectError::MapKeyNotFound(key_b.to_string()))
+        );
+
+        // Test setting an existing entry and inserting a new one
+        assert_eq!(
+            target.set_map_value_serialized(&mut world, key_a, "{\"i32\":2}"),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(
+            target.set_map_value_serialized(&mut world, key_b, "{\"i32\":3}"),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        let scores = &world.resource::<ResourceScores>().scores;
+        assert_eq!((scores.get("a"), scores.get("b")), (Some(&2), Some(&3)));
+
+        // Test the errors for a value of the wrong type, an invalid key, and a field that is not a map
+        assert_eq!(
+            target.set_map_value_serialized(&mut world, key_a, "{\"u32\":2}"),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert!(matches!(
+            target.read_map_value_serialized(&world, "not a key"),
+            Err(ReflectError::Deserialize(_))
+        ));
+        assert_eq!(
+            ReflectTarget::new_resource::<ResourceScores>("best")
+                .read_map_value_serialized(&world, key_a),
+            Err(ReflectError::NotAMap)
+        );
+    }
+
+    #[test]
+    fn read_optional_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceLimit {
+            limit: Option<i32>,
+            fallback: i32,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceLimit>();
+        world.init_resource::<ResourceLimit>();
+        let target = ReflectTarget::new_resource::<ResourceLimit>("limit");
+
+        // Test reading both variants
+        assert_eq!(target.read_optional::<i32>(&world), Ok(None));
+        world.resource_mut::<ResourceLimit>().limit = Some(5);
+        assert_eq!(target.read_optional::<i32>(&world), Ok(Some(5)));
+
+        // Test the error when the inner value is a different type
+        assert_eq!(
+            target.read_optional::<u32>(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+
+        // Test the error when the field is not an `Option`
+        let target = ReflectTarget::new_resource::<ResourceLimit>("fallback");
+        assert_eq!(
+            target.read_optional::<i32>(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn set_value_checked_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceD>();
+        let target = ReflectTarget::new_resource::<ResourceD>("level");
+        assert_eq!(target.read_range_attribute(&world), Ok(Some(0.0..=10.0)));
+
+        // Test the attribute is found through a field index, and the root has no attribute
+        assert_eq!(
+            ReflectTarget::new_resource::<ResourceD>("#0").read_range_attribute(&world),
+            Ok(Some(0.0..=10.0))
+        );
+        assert_eq!(
+            ReflectTarget::new_resource::<ResourceD>("").read_range_attribute(&world),
+            Ok(None)
+        );
+
+        // Test a value in range is set, and one out of range is rejected
+        assert_eq!(
+            target.set_value_checked(&mut world, 7),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(
+            target.set_value_checked(&mut world, 11),
+            Err(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(world.resource::<ResourceD>().level, 7);
+
+        // Test fields without a range attribute accept any value
+        let target = ReflectTarget::new_resource::<ResourceD>("unbounded");
+        assert_eq!(target.read_range_attribute(&world), Ok(None));
+        assert_eq!(
+            target.set_value_checked(&mut world, 11),
+            Ok(ReflectSetSuccess::Changed)
+        );
+    }
+
+    #[test]
+    fn set_value_serialized_validated_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceD {
+            level: 2,
+            unbounded: 5,
+        });
+        let target = ReflectTarget::new_resource::<ResourceD>("level");
+        let validate = |owner: &dyn Reflect| {
+            owner
+                .downcast_ref::<ResourceD>()
+                .is_some_and(|resource| resource.level <= resource.unbounded)
+        };
+
+        // Test a valid value is kept
+        assert_eq!(
+            target.set_value_serialized_validated(&mut world, "{\"i32\":4}", validate),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceD>().level, 4);
+
+        // Test an invalid value is rolled back
+        assert_eq!(
+            target.set_value_serialized_validated(&mut world, "{\"i32\":6}", validate),
+            Err(ReflectError::ValidationFailed)
+        );
+        assert_eq!(world.resource::<ResourceD>().level, 4);
+    }
+
+    #[test]
+    fn update_value_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        assert_eq!(
+            target.update_value(&mut world, |value: i32| value + 1),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(
+            target.update_value(&mut world, |value: i32| value + 1),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().value, 2);
+
+        // Test returning the same value makes no changes, and a wrong type is rejected
+        assert_eq!(
+            target.update_value(&mut world, |value: i32| value),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+        assert_eq!(
+            target.update_value(&mut world, |value: u32| value + 1),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn read_variant_struct_field_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceB {
+            motion: EnumC::Custom { speed: 1.5 },
+            ..default()
+        });
+        let target = ReflectTarget::new_resource::<ResourceB>("motion");
+
+        assert_eq!(
+            target.read_variant_struct_field::<f32>(&world, "speed"),
+            Ok(1.5)
+        );
+        assert_eq!(
+            ReflectTarget::new_resource::<ResourceB>("motion.speed").read_value::<f32>(&mut world),
+            Ok(1.5)
+        );
+        assert_eq!(
+            target.read_variant_struct_field::<u32>(&world, "speed"),
+            Err(ReflectError::InvalidDowncast)
+        );
+
+        // Test the error when a different variant is active
+        world.resource_mut::<ResourceB>().motion = EnumC::Off;
+        assert_eq!(
+            target.read_variant_struct_field::<f32>(&world, "speed"),
+            Err(ReflectError::VariantFieldNotFound {
+                variant: "Off".to_string(),
+                field: "speed".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn new_resource_checked_works() {
+        let world = create_world();
+        assert_eq!(
+            ReflectTarget::new_resource_checked::<ResourceA>(&world, "value"),
+            ReflectTarget::new_resource::<ResourceA>("value")
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "2 registered types have the type path")]
+    fn new_resource_checked_panics_on_duplicate_type_path() {
+        let world = create_world();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<DuplicatePathA>();
+            type_registry.register::<DuplicatePathB>();
+        }
+        ReflectTarget::new_resource_checked::<DuplicatePathA>(&world, "value");
+    }
+
+    #[test]
+    fn vec3_round_trip_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourcePosition {
+            position: Vec3,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourcePosition>();
+        world.insert_resource(ResourcePosition {
+            position: Vec3::new(1.0, 2.0, 3.0),
+        });
+        let target = ReflectTarget::new_resource::<ResourcePosition>("position");
+
+        assert_eq!(target.read_vec3(&world), Ok([1.0, 2.0, 3.0]));
+        assert_eq!(
+            target.set_vec3(&mut world, [4.0, 5.0, 6.0]),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(target.read_vec3(&world), Ok([4.0, 5.0, 6.0]));
+        assert_eq!(
+            world.resource::<ResourcePosition>().position,
+            Vec3::new(4.0, 5.0, 6.0)
+        );
+
+        // Test the error when the field is a different math type
+        assert_eq!(target.read_vec2(&world), Err(ReflectError::InvalidDowncast));
+        assert_eq!(
+            target.set_vec4(&mut world, [0.0; 4]),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn color_hex_round_trip_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC {
+            color: Color::srgb_u8(255, 136, 0),
+            ..default()
+        });
+        let target = ReflectTarget::new_resource::<ResourceC>("color");
+
+        assert_eq!(target.read_color_hex(&world), Ok("#FF8800".to_string()));
+
+        // Test setting a color with alpha, and reading it back
+        target.set_color_hex(&mut world, "#11223380").unwrap();
+        assert_eq!(
+            world.resource::<ResourceC>().color,
+            Color::srgba_u8(0x11, 0x22, 0x33, 0x80)
+        );
+        assert_eq!(target.read_color_hex(&world), Ok("#11223380".to_string()));
+
+        // Test the error when the hex string is invalid
+        assert!(matches!(
+            target.set_color_hex(&mut world, "#nothex"),
+            Err(ReflectError::Deserialize(_))
+        ));
+
+        // Test the error when the field is not a `Color`
+        let target = ReflectTarget::new_resource::<ResourceC>("value");
+        assert_eq!(
+            target.read_color_hex(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert_eq!(
+            target.set_color_hex(&mut world, "#FF8800"),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn field_reflect_kind_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        world.init_resource::<ResourceB>();
+        world.init_resource::<ResourceC>();
+
+        // Test a bool field
+        let target = ReflectTarget::new_resource::<ResourceC>("enabled");
+        assert_eq!(
+            target.field_reflect_kind(&world),
+            Ok(FieldKind::Value(TypeId::of::<bool>()))
+        );
+        assert_eq!(target.is_bool(&world), Ok(true));
+        assert_eq!(target.is_numeric(&world), Ok(false));
+        assert_eq!(target.is_enum(&world), Ok(false));
+
+        // Test a numeric field
+        let target = ReflectTarget::new_resource::<ResourceA>("ratio");
+        assert_eq!(target.is_numeric(&world), Ok(true));
+        assert_eq!(target.is_bool(&world), Ok(false));
+
+        // Test an enum field
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(target.field_reflect_kind(&world), Ok(FieldKind::Enum));
+        assert_eq!(target.is_enum(&world), Ok(true));
+
+        // Test a struct field, and a tuple field
+        let target = ReflectTarget::new_resource::<ResourceA>("");
+        assert_eq!(target.field_reflect_kind(&world), Ok(FieldKind::Struct));
+        let target = ReflectTarget::new_resource::<ResourceB>("value2");
+        assert_eq!(target.field_reflect_kind(&world), Ok(FieldKind::Tuple));
+    }
+
+    #[test]
+    fn set_value_coerced_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceNumbers {
+            big: i64,
+            tiny: i8,
+            ratio: f64,
+            enabled: bool,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceNumbers>();
+        world.init_resource::<ResourceNumbers>();
+
+        // Test an `i32` value is widened to an `i64` field
+        let target = ReflectTarget::new_resource::<ResourceNumbers>("big");
+        assert_eq!(
+            target.set_value_coerced(&mut world, 5_i32),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceNumbers>().big, 5);
+
+        // Test an `i64` value that does not fit an `i8` field is rejected
+        let target = ReflectTarget::new_resource::<ResourceNumbers>("tiny");
+        assert_eq!(
+            target.set_value_coerced(&mut world, 1000_i64),
+            Err(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(world.resource::<ResourceNumbers>().tiny, 0);
+
+        // Test a `u64` value an `f64` field can not represent exactly is rejected, rather than rounded
+        let target = ReflectTarget::new_resource::<ResourceNumbers>("ratio");
+        assert_eq!(
+            target.set_value_coerced(&mut world, u64::MAX),
+            Err(ReflectError::NumericOutOfRange)
+        );
+        assert_eq!(world.resource::<ResourceNumbers>().ratio, 0.);
+
+        // Test non-numeric values are set as-is
+        let target = ReflectTarget::new_resource::<ResourceNumbers>("enabled");
+        assert_eq!(
+            target.set_value_coerced(&mut world, true),
+            Ok(ReflectSetSuccess::Changed)
+        );
+    }
+
+    #[test]
+    fn describe_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceC {
+            value: 5,
+            ..default()
+        });
+
+        let description = ReflectTarget::new_resource::<ResourceC>("value").describe(&mut world);
+        assert!(description.contains(ResourceC::type_path()));
+        assert!(description.contains("field type: i32"));
+        assert!(description.contains("value: {\"i32\":5}"));
+        assert!(description.contains("accessible: yes"));
+
+        // Test an inaccessible field is described with its error
+        let description = ReflectTarget::new_resource::<ResourceC>("missing").describe(&mut world);
+        assert!(description.contains("accessible: no"));
+    }
+
+    #[test]
+    fn duration_secs_round_trip_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceDurations {
+            cooldown: Duration,
+            timer: Timer,
+            count: i32,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceDurations>();
+        world.init_resource::<ResourceDurations>();
+
+        // Test a `Duration` field
+        let target = ReflectTarget::new_resource::<ResourceDurations>("cooldown");
+        assert_eq!(
+            target.set_duration_secs(&mut world, 1.5),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(
+            world.resource::<ResourceDurations>().cooldown,
+            Duration::from_millis(1500)
+        );
+        assert_eq!(target.read_duration_secs(&world), Ok(1.5));
+        assert_eq!(
+            target.set_duration_secs(&mut world, 1.5),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+
+        // Test a `Timer` field
+        let target = ReflectTarget::new_resource::<ResourceDurations>("timer");
+        assert_eq!(
+            target.set_duration_secs(&mut world, 0.25),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(
+            world.resource::<ResourceDurations>().timer.duration(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(target.read_duration_secs(&world), Ok(0.25));
+
+        // Test the errors for invalid values and fields
+        assert_eq!(
+            target.set_duration_secs(&mut world, -1.),
+            Err(ReflectError::NumericOutOfRange)
+        );
+        let target = ReflectTarget::new_resource::<ResourceDurations>("count");
+        assert_eq!(
+            target.read_duration_secs(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert_eq!(
+            target.set_duration_secs(&mut world, 1.),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn array_round_trip_works() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceValues {
+            values: [i32; 3],
+            count: i32,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceValues>();
+        world.init_resource::<ResourceValues>();
+        let target = ReflectTarget::new_resource::<ResourceValues>("values");
+
+        assert_eq!(target.read_array::<i32>(&world), Ok(vec![0, 0, 0]));
+        assert_eq!(
+            target.set_array(&mut world, vec![1, 2, 3]),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceValues>().values, [1, 2, 3]);
+        assert_eq!(target.read_array::<i32>(&world), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            target.set_array(&mut world, vec![1, 2, 3]),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+
+        // Test the errors for a length mismatch and the wrong element type, which leave the array unchanged
+        assert_eq!(
+            target.set_array(&mut world, vec![4, 5]),
+            Err(ReflectError::ArrayLengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            target.set_array(&mut world, vec![4_u8, 5, 6]),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert_eq!(
+            target.read_array::<u8>(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert_eq!(world.resource::<ResourceValues>().values, [1, 2, 3]);
+
+        // Test the error when the field is not an array
+        let target = ReflectTarget::new_resource::<ResourceValues>("count");
+        assert_eq!(
+            target.read_array::<i32>(&world),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn value_bytes_round_trip_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        world.init_resource::<ResourceB>();
+
+        // Read the bytes of an enum variant with data from one field, and set them on another
+        world.resource_mut::<ResourceB>().theme = EnumB::Custom(7);
+        let target = ReflectTarget::new_resource::<ResourceB>("theme");
+        let bytes = target.read_value_bytes(&world).unwrap();
+        assert!(bytes.len() < target.read_value_serialized(&mut world).unwrap().len());
+
+        world.resource_mut::<ResourceB>().theme = EnumB::None;
+        assert_eq!(
+            target.set_value_bytes(&mut world, &bytes),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceB>().theme, EnumB::Custom(7));
+
+        // Test the error when the bytes are invalid
+        assert!(matches!(
+            target.set_value_bytes(&mut world, &[255, 255]),
+            Err(ReflectError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn read_value_serialized_bare_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            value: 5,
+            mode: EnumA::B,
+            ..default()
+        });
+        world.insert_resource(ResourceC {
+            enabled: true,
+            ..default()
+        });
+
+        // Test values are serialized without the type path wrapper
+        let target = ReflectTarget::new_resource::<ResourceC>("enabled");
+        assert_eq!(
+            target.read_value_serialized_bare(&world),
+            Ok("true".to_string())
+        );
+        assert_eq!(
+            ReflectTarget::new_resource::<ResourceA>("value").read_value_serialized_bare(&world),
+            Ok("5".to_string())
+        );
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(
+            target.read_value_serialized_bare(&world),
+            Ok("B".to_string())
+        );
+
+        // Test the bare value can be set back
+        assert_eq!(
+            target.set_value_serialized_typed(&mut world, "B"),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+    }
+
+    #[test]
+    fn validate_serialized_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        // Test a valid value passes without being set
+        assert_eq!(target.validate_serialized(&world, "{\"i32\":5}"), Ok(()));
+        assert_eq!(world.resource::<ResourceA>().value, 0);
+
+        // Test a value of the wrong type
+        assert_eq!(
+            target.validate_serialized(&world, "{\"u32\":5}"),
+            Err(ReflectError::InvalidDowncast)
+        );
+
+        // Test malformed input
+        assert!(matches!(
+            target.validate_serialized(&world, "{\"i32\":"),
+            Err(ReflectError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn set_value_number_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        // Test setting an `i32` field truncates the value
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(
+            target.set_value_number(&mut world, -3.7),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().value, -3);
+
+        // Test setting an `f32` field
+        let target = ReflectTarget::new_resource::<ResourceA>("ratio");
+        assert_eq!(
+            target.set_value_number(&mut world, 0.25),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().ratio, 0.25);
+
+        // Test the error when the field is not a number
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(
+            target.set_value_number(&mut world, 1.),
+            Err(ReflectError::UnsupportedNumericType)
+        );
+    }
+
+    #[test]
+    fn set_string_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("name");
+        assert_eq!(
+            target.set_string(&mut world, "foo"),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().name, "foo");
+
+        // Test setting the same value reports no changes
+        assert_eq!(
+            target.set_string(&mut world, "foo"),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+
+        // Test the error when the field is not a `String`
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(
+            target.set_string(&mut world, "foo"),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn diagnose_path_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceB>();
+
+        // Test a valid path
+        let target = ReflectTarget::new_resource::<ResourceB>("value2.0");
+        assert_eq!(target.diagnose_path(&world), Ok(()));
+
+        // Test the failure is reported at the final segment
+        let target = ReflectTarget::new_resource::<ResourceB>("value2.0.nonexistent");
+        let diagnostic = target.diagnose_path(&world).unwrap_err();
+        assert_eq!(diagnostic.resolved, ".value2.0");
+        assert_eq!(diagnostic.failed_segment, ".nonexistent");
+        assert_eq!(diagnostic.type_path, "u32");
+
+        // Test the error when the resource does not exist
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        let diagnostic = target.diagnose_path(&world).unwrap_err();
+        assert_eq!(
+            diagnostic.error,
+            ReflectError::ResourceDoesNotExist.to_string()
+        );
+    }
+
+    #[test]
+    fn set_value_serialized_typed_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        world.init_resource::<ResourceC>();
+
+        // Test setting a bool from a bare value
+        let target = ReflectTarget::new_resource::<ResourceC>("enabled");
+        assert_eq!(
+            target.set_value_serialized_typed(&mut world, "true"),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert!(world.resource::<ResourceC>().enabled);
+        assert_eq!(
+            target.set_value_serialized_typed(&mut world, "true"),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+
+        // Test setting an enum from a bare variant name
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(
+            target.set_value_serialized_typed(&mut world, "B"),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().mode, EnumA::B);
+
+        // Test the error when the value does not match the field's type
+        assert!(matches!(
+            target.set_value_serialized_typed(&mut world, "C"),
+            Err(ReflectError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn differs_from_serialized_and_default_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        // Test an unmodified value
+        assert_eq!(
+            target.differs_from_serialized(&mut world, "{\"i32\":0}"),
+            Ok(false)
+        );
+        assert_eq!(target.differs_from_default(&world), Ok(false));
+
+        // Test a modified value
+        world.resource_mut::<ResourceA>().value = 2;
+        assert_eq!(
+            target.differs_from_serialized(&mut world, "{\"i32\":0}"),
+            Ok(true)
+        );
+        assert_eq!(
+            target.differs_from_serialized(&mut world, "{\"i32\":2}"),
+            Ok(false)
+        );
+        assert_eq!(target.differs_from_default(&world), Ok(true));
+    }
+
+    #[test]
+    fn read_fields_serialized_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("");
+        assert_eq!(
+            target.read_fields_serialized(&world),
+            Ok(vec![
+                ("value".to_string(), "{\"i32\":0}".to_string()),
+                (
+                    "mode".to_string(),
+                    "{\"bevy_reflect_utils::reflect_target::tests::EnumA\":A}".to_string()
+                ),
+                ("small".to_string(), "{\"u8\":0}".to_string()),
+                ("ratio".to_string(), "{\"f32\":0.0}".to_string()),
+                (
+                    "name".to_string(),
+                    "{\"alloc::string::String\":\"\"}".to_string()
+                ),
+            ])
+        );
+
+        // Test the error when the target is not a struct
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(
+            target.read_fields_serialized(&world),
+            Err(ReflectError::NotAStruct)
+        );
+    }
+
+    #[test]
+    fn component_by_marker_works() {
+        #[derive(Component)]
+        struct Player;
+
+        let mut world = create_world();
+        let target = ReflectTarget::new_component_by_marker::<ComponentA, Player>("value");
+
+        // Test the error before the marker was ever used
+        assert_eq!(
+            target.read_value::<i32>(&mut world),
+            Err(ReflectError::EntityNotFound)
+        );
+        assert!(!target.owner_exists(&world));
+
+        // Test the entity is found through its unique marker
+        world.spawn(ComponentA { value: 1 });
+        let player = world.spawn((ComponentA { value: 2 }, Player)).id();
+        assert_eq!(target.read_value::<i32>(&mut world), Ok(2));
+        assert_eq!(
+            target.set_value(&mut world, 5),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.get::<ComponentA>(player).unwrap().value, 5);
+
+        // Test the target follows a respawned entity
+        world.despawn(player);
+        assert_eq!(
+            target.read_value::<i32>(&mut world),
+            Err(ReflectError::EntityNotFound)
+        );
+        world.spawn((ComponentA { value: 3 }, Player));
+        assert_eq!(target.read_value::<i32>(&mut world), Ok(3));
+
+        // Test the error when more than one entity has the marker
+        world.spawn(Player);
+        assert_eq!(
+            target.read_value::<i32>(&mut world),
+            Err(ReflectError::AmbiguousEntity)
+        );
+    }
+
+    #[test]
+    fn for_each_field_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("");
+        let mut names = Vec::new();
+        assert_eq!(
+            target.for_each_field(&world, |name, _| names.push(name.to_string())),
+            Ok(())
+        );
+        assert_eq!(names, ["value", "mode", "small", "ratio", "name"]);
+
+        // Test the error when the target is not a struct
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(
+            target.for_each_field(&world, |_, _| {}),
+            Err(ReflectError::NotAStruct)
+        );
+    }
+
+    #[test]
+    fn set_if_different_serialized_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        // Test setting a different value
+        let result = target
+            .set_if_different_serialized(&mut world, "{\"i32\":5}")
+            .unwrap();
+        assert_eq!(result.success, ReflectSetSuccess::Changed);
+        assert!(!result.already_matched());
+        assert_eq!(world.resource::<ResourceA>().value, 5);
+
+        // Test setting the same value reports that it already matched
+        let result = target
+            .set_if_different_serialized(&mut world, "{\"i32\":5}")
+            .unwrap();
+        assert_eq!(result.success, ReflectSetSuccess::NoChanges);
+        assert!(result.already_matched());
+    }
+
+    #[test]
+    fn set_if_different_serialized_matches_without_partial_eq() {
+        // NOTE: Does not reflect `PartialEq`, so `reflect_partial_eq` returns `None`
+        #[derive(Reflect, Default, Clone, serde::Serialize, serde::Deserialize)]
+        #[reflect_value(Default, Serialize, Deserialize)]
+        struct OpaqueValue(u32);
+
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct ResourceOpaque {
+            value: OpaqueValue,
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<ResourceOpaque>();
+        world.init_resource::<ResourceOpaque>();
+        let target = ReflectTarget::new_resource::<ResourceOpaque>("value");
+        let value = target.read_value_serialized(&mut world).unwrap();
+
+        // Test setting the same value agrees with `set_value_serialized`
+        assert_eq!(
+            target.set_value_serialized(&mut world, &value),
+            Ok(ReflectSetSuccess::NoChanges)
+        );
+        let result = target
+            .set_if_different_serialized(&mut world, &value)
+            .unwrap();
+        assert!(result.already_matched());
+    }
+
+    #[test]
+    fn targeted_error_works() {
+        let mut world = create_world();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        // Test the wrapped error prints the target's type path and field
+        let err = target
+            .read_value_with_context::<i32>(&mut world)
+            .unwrap_err();
+        assert_eq!(err.error, ReflectError::ResourceDoesNotExist);
+        assert_eq!(
+            err.to_string(),
+            "bevy_reflect_utils::reflect_target::tests::ResourceA \"value\": Resource does not exist"
+        );
+
+        // Test component targets include the entity
+        let entity = world.spawn_empty().id();
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "value");
+        let err = target
+            .set_value_with_context(&mut world, 1_i32)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "bevy_reflect_utils::reflect_target::tests::ComponentA \"value\" on entity {entity}: Entity does not have component"
+            )
+        );
+    }
+
+    #[test]
+    fn set_value_registering_works() {
+        let mut world = create_world();
+        world.init_resource::<NonRegisteredResource>();
+        let target = ReflectTarget::new_resource::<NonRegisteredResource>("value");
+
+        // Test the type is not registered yet
+        assert_eq!(
+            target.set_value(&mut world, 1_i32),
+            Err(ReflectError::TypeRegistrationNotFound)
+        );
+
+        assert_eq!(
+            target.set_value_registering::<NonRegisteredResource, _>(&mut world, 1_i32),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<NonRegisteredResource>().value, 1);
+        assert!(world
+            .resource::<AppTypeRegistry>()
+            .read()
+            .contains(TypeId::of::<NonRegisteredResource>()));
+    }
+
+    #[test]
+    fn toggle_reflect_enum_named_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+
+        assert_eq!(
+            target.toggle_reflect_enum_named(&mut world, EnumDirection::Forward),
+            Ok((ReflectSetSuccess::Changed, "B".to_string()))
+        );
+        assert_eq!(world.resource::<ResourceA>().mode, EnumA::B);
+        assert_eq!(
+            target.read_enum_variant_name(&mut world),
+            Ok("B".to_string())
+        );
+
+        // Test toggling past the last variant reports no changes and the current variant
+        assert_eq!(
+            target.toggle_reflect_enum_named(&mut world, EnumDirection::Forward),
+            Ok((ReflectSetSuccess::NoChanges, "B".to_string()))
+        );
+    }
+
+    #[test]
+    fn set_variant_field_works() {
+        let mut world = create_world();
+        world.init_resource::<ResourceB>();
+        let target = ReflectTarget::new_resource::<ResourceB>("theme");
+
+        // Test switching from a unit variant to a tuple variant and setting its field
+        assert_eq!(
+            target.set_variant_field(&mut world, "Custom", ".0", Box::new(5_u32)),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceB>().theme, EnumB::Custom(5));
+
+        // Test setting the field when the variant is already active
+        assert_eq!(
+            target.set_variant_field(&mut world, "Custom", ".0", Box::new(6_u32)),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceB>().theme, EnumB::Custom(6));
+
+        // Test the error when the variant does not exist
+        assert_eq!(
+            target.set_variant_field(&mut world, "Missing", ".0", Box::new(6_u32)),
+            Err(ReflectError::VariantNotFound("Missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn path_segments_works() {
+        let segments = |path: &str| {
+            ReflectTarget::new_resource::<ResourceB>(path)
+                .path_segments()
+                .into_iter()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(segments("theme.0"), ["theme", "0"]);
+        assert_eq!(segments("value"), ["value"]);
+        assert!(segments("").is_empty());
+    }
+
+    #[test]
+    fn retarget_entity_works() {
+        let mut world = create_world();
+        let entity_a = world.spawn(ComponentA { value: 1 }).id();
+        let entity_b = world.spawn(ComponentA { value: 2 }).id();
+
+        let target = ReflectTarget::new_component::<ComponentA>(entity_a, "value");
+        let retargeted = target.retarget_entity(entity_b);
+        assert_eq!(
+            retargeted,
+            ReflectTarget::new_component::<ComponentA>(entity_b, "value")
+        );
+        assert_eq!(target.read_value::<i32>(&mut world), Ok(1));
+        assert_eq!(retargeted.read_value::<i32>(&mut world), Ok(2));
+
+        // Test resource targets are unchanged
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(target.retarget_entity(entity_b), target);
+    }
+
+    #[test]
+    fn owner_exists_works_for_components() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentA::default()).id();
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "value");
+        assert!(target.owner_exists(&world));
+
+        // Test it does not resolve the field path
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "not_a_field");
+        assert!(target.owner_exists(&world));
+
+        world.despawn(entity);
+        assert!(!target.owner_exists(&world));
+    }
+
+    #[test]
+    fn owner_exists_works_for_resources() {
+        let mut world = create_world();
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert!(!target.owner_exists(&world));
+
+        world.init_resource::<ResourceA>();
+        assert!(target.owner_exists(&world));
+
+        world.remove_resource::<ResourceA>();
+        assert!(!target.owner_exists(&world));
     }
 }
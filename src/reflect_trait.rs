@@ -2,10 +2,12 @@ use std::any::TypeId;
 
 use bevy::{
     prelude::*,
-    reflect::{TypeData, TypeRegistry},
+    reflect::{TypeData, TypeRegistration, TypeRegistry},
 };
 
-use crate::ReflectError;
+use crate::{
+    get_reflect_component, with_resource_reflect_field, ReflectError, ReflectFieldAccessor,
+};
 
 pub fn with_reflect_trait_on_entity_world<T: TypeData, R>(
     world: &mut World,
@@ -29,12 +31,15 @@ pub fn with_reflect_trait_on_entity<T: TypeData, R>(
     type_id: TypeId,
     get_fn: impl FnOnce(&dyn Reflect, &T) -> R,
 ) -> Result<R, ReflectError> {
-    let reflect_trait = type_registry
-        .get_type_data::<T>(type_id)
+    let registration = type_registry
+        .get(type_id)
         .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_component = type_registry
-        .get_type_data::<ReflectComponent>(type_id)
+    let reflect_trait = registration
+        .data::<T>()
         .ok_or(ReflectError::TypeRegistrationNotFound)?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
     let reflect_value = reflect_component
         .reflect(entity_ref)
         .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
@@ -64,23 +69,89 @@ pub fn with_reflect_trait_on_entity_mut<T: TypeData, R>(
     type_id: TypeId,
     get_fn: impl FnOnce(&mut dyn Reflect, &T) -> R,
 ) -> Result<R, ReflectError> {
-    let reflect_trait = type_registry
-        .get_type_data::<T>(type_id)
+    let registration = type_registry
+        .get(type_id)
         .ok_or(ReflectError::TypeRegistrationNotFound)?;
-    let reflect_component = type_registry
-        .get_type_data::<ReflectComponent>(type_id)
+    let reflect_trait = registration
+        .data::<T>()
         .ok_or(ReflectError::TypeRegistrationNotFound)?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or(ReflectError::TypeRegistrationInvalidCast)?;
     let mut reflect_value = reflect_component
         .reflect_mut(entity_mut)
         .ok_or(ReflectError::EntityDoesNotHaveComponent)?;
     Ok(get_fn(reflect_value.as_reflect_mut(), reflect_trait))
 }
 
+/// Runs a closure with a reflected trait on the value of a field on a resource.
+///
+/// The trait's type data is looked up on the concrete type of the field, rather than the declared type, so the same
+/// call works for any field type that reflects the trait. If the field's type does not reflect the trait, wrappers with
+/// `ReflectFieldAccessor` type data are followed to their inner value, so a newtype around a `Box<dyn Trait>` reaches
+/// the boxed value.
+///
+/// Returns `ReflectError::TypeRegistrationNotFound` if the field's type does not reflect the trait.
+///
+/// ```ignore
+/// let area = reflect_resource_read_trait_field::<ReflectShape, _>(
+///     world,
+///     TypeId::of::<MyResource>(),
+///     "shape",
+///     |reflect_value, reflect_shape| reflect_shape.get(reflect_value).map(|shape| shape.area()),
+/// );
+/// ```
+pub fn reflect_resource_read_trait_field<T: TypeData, R>(
+    world: &World,
+    resource_type_id: TypeId,
+    path: impl AsRef<str>,
+    read_fn: impl FnOnce(&dyn Reflect, &T) -> R,
+) -> Result<R, ReflectError> {
+    let path = path.as_ref();
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    with_resource_reflect_field(world, resource_type_id, path, |field| {
+        let mut value = field;
+        loop {
+            if let Some(reflect_trait) = type_registry.get_type_data::<T>(value.type_id()) {
+                return Ok(read_fn(value, reflect_trait));
+            }
+            value = type_registry
+                .get_type_data::<ReflectFieldAccessor>(value.type_id())
+                .and_then(|accessor| accessor.get(value))
+                .ok_or(ReflectError::TypeRegistrationNotFound)?;
+        }
+    })?
+}
+
 pub fn reflect_find_trait_on_entity<T: TypeData, R>(
     world: &mut World,
     entity: Entity,
     trait_handler: impl Fn(&dyn Reflect, &T) -> Option<R>,
 ) -> Result<Option<R>, ReflectError> {
+    reflect_trait_find_one_with_type(world, entity, trait_handler)
+        .map(|result| result.map(|(_, value)| value))
+}
+
+pub fn reflect_trait_find_one<T: TypeData, R>(
+    world: &mut World,
+    entity: Entity,
+    mut callback: impl FnMut(&dyn Reflect, &T) -> Option<R>,
+) -> Result<Option<R>, ReflectError> {
+    let mut return_value: Option<R> = None;
+    let result = reflect_trait_iter(world, entity, |reflect_value, reflect_trait| {
+        return_value = callback(reflect_value, reflect_trait);
+        return_value.is_none() // `false` to stop iterating if return value is `Some`, `true` to keep iterating
+    });
+    result.map(|_| return_value)
+}
+
+/// Same as `reflect_trait_find_one`, but also returns the `TypeId` of the component that returned `Some`.
+pub fn reflect_trait_find_one_with_type<T: TypeData, R>(
+    world: &mut World,
+    entity: Entity,
+    mut callback: impl FnMut(&dyn Reflect, &T) -> Option<R>,
+) -> Result<Option<(TypeId, R)>, ReflectError> {
     let entity_ref = world
         .get_entity(entity)
         .ok_or(ReflectError::EntityNotFound)?;
@@ -88,7 +159,7 @@ pub fn reflect_find_trait_on_entity<T: TypeData, R>(
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
-    // Find the first result that returns `Some`
+    // Find the first result that returns `Some`, keeping the type id of the component that returned it
     let result = entity_ref
         .archetype()
         .components()
@@ -104,30 +175,18 @@ pub fn reflect_find_trait_on_entity<T: TypeData, R>(
         })
         .filter_map(|(type_id, reflect_trait)| {
             let reflect_component = type_registry.get_type_data::<ReflectComponent>(type_id);
-            reflect_component.map(|reflect_component| (reflect_trait, reflect_component))
+            reflect_component.map(|reflect_component| (type_id, reflect_trait, reflect_component))
         })
-        .find_map(|(reflect_trait, reflect_component)| {
+        .find_map(|(type_id, reflect_trait, reflect_component)| {
             reflect_component
                 .reflect(entity_ref)
-                .and_then(|reflect_value| trait_handler(reflect_value, reflect_trait))
+                .and_then(|reflect_value| callback(reflect_value, reflect_trait))
+                .map(|result| (type_id, result))
         });
 
     Ok(result)
 }
 
-pub fn reflect_trait_find_one<T: TypeData, R>(
-    world: &mut World,
-    entity: Entity,
-    mut callback: impl FnMut(&dyn Reflect, &T) -> Option<R>,
-) -> Result<Option<R>, ReflectError> {
-    let mut return_value: Option<R> = None;
-    let result = reflect_trait_iter(world, entity, |reflect_value, reflect_trait| {
-        return_value = callback(reflect_value, reflect_trait);
-        return_value.is_none() // `false` to stop iterating if return value is `Some`, `true` to keep iterating
-    });
-    result.map(|_| return_value)
-}
-
 pub fn reflect_trait_once<T: TypeData>(
     world: &mut World,
     entity: Entity,
@@ -164,6 +223,38 @@ pub fn reflect_trait_mut_expect_once<T: TypeData>(
     }
 }
 
+/// Utility that calls a closure on the only component on an entity that reflects a trait.
+///
+/// Returns `ReflectError::AmbiguousTrait` if more than one component reflects the trait, or
+/// `ReflectError::EntityDoesNotHaveComponent` if none do. The closure is not called in either case.
+pub fn reflect_trait_expect_unique<T: TypeData, R>(
+    world: &mut World,
+    entity: Entity,
+    callback: impl FnOnce(&dyn Reflect, &T) -> R,
+) -> Result<R, ReflectError> {
+    // Count the matching components, stopping as soon as we know the trait is ambiguous
+    let mut count = 0;
+    reflect_trait_iter::<T>(world, entity, |_, _| {
+        count += 1;
+        count < 2
+    })?;
+
+    match count {
+        0 => Err(ReflectError::EntityDoesNotHaveComponent),
+        1 => {
+            let mut callback = Some(callback);
+            let mut return_value: Option<R> = None;
+            reflect_trait_once::<T>(world, entity, |reflect_value, reflect_trait| {
+                return_value = callback
+                    .take()
+                    .map(|callback| callback(reflect_value, reflect_trait));
+            })?;
+            return_value.ok_or(ReflectError::EntityDoesNotHaveComponent)
+        }
+        _ => Err(ReflectError::AmbiguousTrait),
+    }
+}
+
 /// Utility that calls a closure on all components that reflect a trait, with immutable access.
 ///
 /// The closure can return `true` to keep iterating, `false` to stop.
@@ -201,7 +292,7 @@ pub fn reflect_trait_mut_expect_once<T: TypeData>(
 pub fn reflect_trait_iter<T: TypeData>(
     world: &mut World,
     entity: Entity,
-    mut callback: impl FnMut(&dyn Reflect, &T) -> bool,
+    callback: impl FnMut(&dyn Reflect, &T) -> bool,
 ) -> Result<(), ReflectError> {
     let entity_ref = world
         .get_entity(entity)
@@ -210,7 +301,23 @@ pub fn reflect_trait_iter<T: TypeData>(
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
-    // Find the first result that returns `Some`
+    reflect_trait_iter_ref(entity_ref, &type_registry, world, callback);
+
+    Ok(())
+}
+
+/// Utility that calls a closure on all components that reflect a trait, from pre-obtained references.
+///
+/// Works the same as `reflect_trait_iter`, but only needs readonly access to the world, so it can be used from systems
+/// that don't have exclusive world access.
+///
+/// The closure can return `true` to keep iterating, `false` to stop.
+pub fn reflect_trait_iter_ref<T: TypeData>(
+    entity_ref: EntityRef,
+    type_registry: &TypeRegistry,
+    world: &World,
+    mut callback: impl FnMut(&dyn Reflect, &T) -> bool,
+) {
     entity_ref
         .archetype()
         .components()
@@ -236,6 +343,57 @@ pub fn reflect_trait_iter<T: TypeData>(
                 false
             }
         });
+}
+
+/// Utility that calls a closure on all components that reflect a trait, with immutable access, in a deterministic
+/// order.
+///
+/// Works the same as `reflect_trait_iter`, except components are visited sorted by their type path, rather than in
+/// archetype order. This makes the order stable across runs, at the cost of collecting and sorting the matching
+/// components first.
+///
+/// The closure can return `true` to keep iterating, `false` to stop.
+pub fn reflect_trait_iter_sorted<T: TypeData>(
+    world: &mut World,
+    entity: Entity,
+    mut callback: impl FnMut(&dyn Reflect, &T) -> bool,
+) -> Result<(), ReflectError> {
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(ReflectError::EntityNotFound)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    // Collect the registrations of all components that reflect the trait, sorted by type path
+    let mut registrations: Vec<&TypeRegistration> = entity_ref
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            world
+                .components()
+                .get_info(component_id)
+                .and_then(|component_info| component_info.type_id())
+        })
+        .filter_map(|type_id| type_registry.get(type_id))
+        .filter(|registration| registration.data::<T>().is_some())
+        .collect();
+    registrations.sort_by_key(|registration| registration.type_info().type_path());
+
+    for registration in registrations {
+        let (Some(reflect_trait), Some(reflect_component)) = (
+            registration.data::<T>(),
+            registration.data::<ReflectComponent>(),
+        ) else {
+            continue;
+        };
+        if let Some(reflect_value) = reflect_component.reflect(entity_ref) {
+            let must_continue = callback(reflect_value, reflect_trait);
+            if !must_continue {
+                break;
+            }
+        }
+    }
 
     Ok(())
 }
@@ -271,10 +429,33 @@ pub fn reflect_trait_iter<T: TypeData>(
 ///     error!("No components reflecting `MyTrait` found on entity {entity:?}");
 /// }
 /// ```
+///
+/// NOTE: The `AppTypeRegistry` is removed from the world while the callback runs, so any nested reflection that looks
+/// it up with `world.resource::<AppTypeRegistry>()` will panic. Use `reflect_trait_iter_mut_with_registry` to receive
+/// the `TypeRegistry` in the callback instead.
 pub fn reflect_trait_iter_mut<T: TypeData>(
     world: &mut World,
     entity: Entity,
     mut callback: impl FnMut(&mut dyn Reflect, &T) -> bool,
+) -> Result<(), ReflectError> {
+    reflect_trait_iter_mut_with_registry::<T>(world, entity, |reflect_value, reflect_trait, _| {
+        callback(reflect_value, reflect_trait)
+    })
+}
+
+/// Same as `reflect_trait_iter_mut`, but also passes the `TypeRegistry` to the callback so it can perform nested
+/// reflection, such as serializing the component.
+///
+/// ```rust,ignore
+/// reflect_trait_iter_mut_with_registry::<ReflectMyTrait>(world, entity, |reflect_value, _, type_registry| {
+///     info!("{:?}", serialize_reflect_value(type_registry, reflect_value));
+///     true
+/// });
+/// ```
+pub fn reflect_trait_iter_mut_with_registry<T: TypeData>(
+    world: &mut World,
+    entity: Entity,
+    mut callback: impl FnMut(&mut dyn Reflect, &T, &TypeRegistry) -> bool,
 ) -> Result<(), ReflectError> {
     let entity_ref = world
         .get_entity(entity)
@@ -314,7 +495,11 @@ pub fn reflect_trait_iter_mut<T: TypeData>(
                     .ok_or(ReflectError::EntityNotFound)?;
                 if let Some(mut reflect_value) = reflect_component.reflect_mut(&mut entity_mut) {
                     // Call `callback`. Break the loop if it returns `true`.
-                    let must_continue = callback(reflect_value.as_reflect_mut(), reflect_trait);
+                    let must_continue = callback(
+                        reflect_value.as_reflect_mut(),
+                        reflect_trait,
+                        &type_registry,
+                    );
                     if !must_continue {
                         break;
                     }
@@ -324,3 +509,356 @@ pub fn reflect_trait_iter_mut<T: TypeData>(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{serialize_reflect_value, FieldAccessor};
+
+    #[reflect_trait]
+    trait Shape {
+        fn area(&self) -> f32;
+    }
+
+    #[derive(Reflect, Default)]
+    #[reflect(Shape)]
+    struct Square {
+        size: f32,
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> f32 {
+            self.size * self.size
+        }
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Shape)]
+    struct ComponentSquare {
+        size: f32,
+    }
+
+    impl Shape for ComponentSquare {
+        fn area(&self) -> f32 {
+            self.size * self.size
+        }
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Shape)]
+    struct ComponentRectangle {
+        width: f32,
+        height: f32,
+    }
+
+    impl Shape for ComponentRectangle {
+        fn area(&self) -> f32 {
+            self.width * self.height
+        }
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        shape: Square,
+        value: u32,
+    }
+
+    #[reflect_trait]
+    trait Effect: Reflect {
+        fn strength(&self) -> f32;
+
+        fn clone_effect(&self) -> Box<dyn Effect>;
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect(Effect)]
+    struct Glow {
+        intensity: f32,
+    }
+
+    impl Effect for Glow {
+        fn strength(&self) -> f32 {
+            self.intensity
+        }
+
+        fn clone_effect(&self) -> Box<dyn Effect> {
+            Box::new(self.clone())
+        }
+    }
+
+    // NOTE: `Box<dyn Effect>` is not reflectable itself, so it is wrapped in an opaque value with an accessor
+    #[derive(Reflect)]
+    #[reflect_value(FieldAccessor)]
+    struct BoxedEffect(Box<dyn Effect>);
+
+    impl Clone for BoxedEffect {
+        fn clone(&self) -> Self {
+            Self(self.0.clone_effect())
+        }
+    }
+
+    impl FieldAccessor for BoxedEffect {
+        fn reflect_inner(&self) -> &dyn Reflect {
+            self.0.as_reflect()
+        }
+    }
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct ResourceB {
+        effect: BoxedEffect,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<Square>();
+        type_registry.write().register::<ComponentSquare>();
+        type_registry.write().register::<ComponentRectangle>();
+        type_registry.write().register::<ResourceB>();
+        type_registry.write().register::<BoxedEffect>();
+        type_registry.write().register::<Glow>();
+
+        world
+    }
+
+    #[test]
+    fn with_reflect_trait_on_entity_errors_when_not_a_component() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentSquare { size: 2. }).id();
+
+        // Test a type reflecting the trait without `ReflectComponent` reports the missing component data
+        assert_eq!(
+            with_reflect_trait_on_entity_world::<ReflectShape, _>(
+                &mut world,
+                entity,
+                TypeId::of::<Square>(),
+                |_, _| ()
+            ),
+            Err(ReflectError::TypeRegistrationInvalidCast)
+        );
+        assert_eq!(
+            with_reflect_trait_on_entity_mut_world::<ReflectShape, _>(
+                &mut world,
+                entity,
+                TypeId::of::<Square>(),
+                |_, _| ()
+            ),
+            Err(ReflectError::TypeRegistrationInvalidCast)
+        );
+    }
+
+    #[test]
+    fn reflect_trait_iter_ref_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn((
+                ComponentSquare { size: 2. },
+                ComponentRectangle {
+                    width: 2.,
+                    height: 3.,
+                },
+            ))
+            .id();
+
+        // Only use readonly access to the world from here on
+        let world = &world;
+        let entity_ref = world.entity(entity);
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut total_area = 0.;
+        reflect_trait_iter_ref::<ReflectShape>(
+            entity_ref,
+            &type_registry,
+            world,
+            |reflect_value, reflect_shape| {
+                if let Some(shape) = reflect_shape.get(reflect_value) {
+                    total_area += shape.area();
+                }
+                true
+            },
+        );
+        assert_eq!(total_area, 10.);
+    }
+
+    #[test]
+    fn reflect_trait_iter_mut_with_registry_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentSquare { size: 2. }).id();
+
+        // Test the callback can mutate the component and perform a nested read using the passed registry
+        let mut serialized = Vec::new();
+        reflect_trait_iter_mut_with_registry::<ReflectShape>(
+            &mut world,
+            entity,
+            |reflect_value, _, type_registry| {
+                if let Some(square) = reflect_value.downcast_mut::<ComponentSquare>() {
+                    square.size = 3.;
+                }
+                serialized.push(serialize_reflect_value(type_registry, reflect_value));
+                true
+            },
+        )
+        .unwrap();
+        assert_eq!(world.get::<ComponentSquare>(entity).unwrap().size, 3.);
+        assert_eq!(serialized.len(), 1);
+        assert!(serialized[0].as_ref().unwrap().contains("size:3.0"));
+    }
+
+    #[test]
+    fn reflect_resource_read_trait_field_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA {
+            shape: Square { size: 2. },
+            value: 1,
+        });
+
+        // Test we can read the area of `ResourceA::shape` through the `Shape` trait
+        let area = reflect_resource_read_trait_field::<ReflectShape, _>(
+            &world,
+            TypeId::of::<ResourceA>(),
+            "shape",
+            |reflect_value, reflect_shape| reflect_shape.get(reflect_value).map(|s| s.area()),
+        );
+        assert_eq!(area, Ok(Some(4.)));
+
+        // Test the error when the field type does not reflect the trait
+        let result = reflect_resource_read_trait_field::<ReflectShape, _>(
+            &world,
+            TypeId::of::<ResourceA>(),
+            "value",
+            |_, _| (),
+        );
+        assert_eq!(result, Err(ReflectError::TypeRegistrationNotFound));
+    }
+
+    #[test]
+    fn reflect_resource_read_trait_field_boxed_trait_object_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceB {
+            effect: BoxedEffect(Box::new(Glow { intensity: 0.5 })),
+        });
+
+        // Test the trait is read on the boxed value's concrete type through the wrapper
+        let strength = reflect_resource_read_trait_field::<ReflectEffect, _>(
+            &world,
+            TypeId::of::<ResourceB>(),
+            "effect",
+            |reflect_value, reflect_effect| {
+                reflect_effect
+                    .get(reflect_value)
+                    .map(|effect| effect.strength())
+            },
+        );
+        assert_eq!(strength, Ok(Some(0.5)));
+    }
+
+    #[test]
+    fn reflect_trait_find_one_with_type_works() {
+        let mut world = create_world();
+        let entity = world
+            .spawn((
+                ComponentSquare { size: 2. },
+                ComponentRectangle {
+                    width: 2.,
+                    height: 3.,
+                },
+            ))
+            .id();
+
+        // Test the type id of the component that matched is returned
+        let result = reflect_trait_find_one_with_type::<ReflectShape, _>(
+            &mut world,
+            entity,
+            |reflect_value, reflect_shape| {
+                reflect_shape
+                    .get(reflect_value)
+                    .map(|shape| shape.area())
+                    .filter(|area| *area > 5.)
+            },
+        );
+        assert_eq!(result, Ok(Some((TypeId::of::<ComponentRectangle>(), 6.))));
+
+        // Test no match returns `None`
+        let result = reflect_trait_find_one_with_type::<ReflectShape, _>(
+            &mut world,
+            entity,
+            |_, _| None::<()>,
+        );
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn reflect_trait_expect_unique_works() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentSquare { size: 3. }).id();
+
+        let area = reflect_trait_expect_unique::<ReflectShape, _>(
+            &mut world,
+            entity,
+            |reflect_value, reflect_shape| reflect_shape.get(reflect_value).map(|s| s.area()),
+        );
+        assert_eq!(area, Ok(Some(9.)));
+    }
+
+    #[test]
+    fn reflect_trait_expect_unique_errors() {
+        let mut world = create_world();
+
+        // Test the error when no component reflects the trait
+        let entity = world.spawn_empty().id();
+        let result = reflect_trait_expect_unique::<ReflectShape, _>(&mut world, entity, |_, _| ());
+        assert_eq!(result, Err(ReflectError::EntityDoesNotHaveComponent));
+
+        // Test the error when more than one component reflects the trait
+        let entity = world
+            .spawn((
+                ComponentSquare { size: 1. },
+                ComponentRectangle {
+                    width: 1.,
+                    height: 2.,
+                },
+            ))
+            .id();
+        let mut was_called = false;
+        let result = reflect_trait_expect_unique::<ReflectShape, _>(&mut world, entity, |_, _| {
+            was_called = true;
+        });
+        assert_eq!(result, Err(ReflectError::AmbiguousTrait));
+        assert!(!was_called);
+    }
+
+    #[test]
+    fn reflect_trait_iter_sorted_visits_in_type_path_order() {
+        let mut world = create_world();
+        let entity = world
+            .spawn((
+                ComponentSquare { size: 3. },
+                ComponentRectangle {
+                    width: 1.,
+                    height: 2.,
+                },
+            ))
+            .id();
+
+        // `ComponentRectangle` sorts before `ComponentSquare`
+        let mut areas: Vec<f32> = vec![];
+        let result = reflect_trait_iter_sorted::<ReflectShape>(
+            &mut world,
+            entity,
+            |reflect_value, reflect_shape| {
+                if let Some(shape) = reflect_shape.get(reflect_value) {
+                    areas.push(shape.area());
+                }
+                true
+            },
+        );
+        assert_eq!(result, Ok(()));
+        assert_eq!(areas, vec![2., 9.]);
+    }
+}
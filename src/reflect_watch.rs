@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use bevy::{ecs::world::Command, prelude::*, utils::HashMap};
+
+use crate::*;
+
+/// Plugin that watches [`ReflectTarget`]s for changes, and sends a [`ReflectFieldChanged<T>`] event when the value of
+/// a watched target changes.
+///
+/// Add one plugin per field type you want to watch:
+///
+/// ```ignore
+/// app.add_plugins(ReflectWatchPlugin::<f32>::default());
+///
+/// // Later, from a system:
+/// let target = ReflectTarget::new_resource::<ExampleResource>("value");
+/// commands.add(target.watch::<f32>());
+/// ```
+pub struct ReflectWatchPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for ReflectWatchPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Reflect + Clone> Plugin for ReflectWatchPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ReflectFieldChanged<T>>()
+            .init_resource::<ReflectWatches<T>>()
+            .add_systems(Last, reflect_watch_system::<T>);
+    }
+}
+
+/// Event sent by [`ReflectWatchPlugin<T>`] when the value of a watched target changes.
+#[derive(Event, Debug, Clone)]
+pub struct ReflectFieldChanged<T: Reflect + Clone> {
+    pub target: ReflectTarget,
+    pub old: T,
+    pub new: T,
+}
+
+/// The last value read from a watched target.
+struct ReflectWatchSnapshot<T> {
+    serialized: String,
+    value: T,
+}
+
+/// Resource storing the targets watched by [`ReflectWatchPlugin<T>`], keyed by target.
+///
+/// The snapshot for a target is `None` until its value has been read successfully for the first time.
+#[derive(Resource)]
+pub struct ReflectWatches<T: Reflect + Clone> {
+    snapshots: HashMap<ReflectTarget, Option<ReflectWatchSnapshot<T>>>,
+}
+
+impl<T: Reflect + Clone> Default for ReflectWatches<T> {
+    fn default() -> Self {
+        Self {
+            snapshots: HashMap::default(),
+        }
+    }
+}
+
+impl<T: Reflect + Clone> ReflectWatches<T> {
+    /// Start watching a target. Does nothing if the target is already watched.
+    pub fn watch(&mut self, target: ReflectTarget) {
+        self.snapshots.entry(target).or_insert(None);
+    }
+
+    /// Stop watching a target. Returns `true` if the target was being watched.
+    pub fn unwatch(&mut self, target: &ReflectTarget) -> bool {
+        self.snapshots.remove(target).is_some()
+    }
+
+    /// Returns whether a target is being watched.
+    pub fn is_watching(&self, target: &ReflectTarget) -> bool {
+        self.snapshots.contains_key(target)
+    }
+}
+
+/// Command that starts watching a target. Returned by [`ReflectTarget::watch`].
+pub struct ReflectWatch<T> {
+    target: ReflectTarget,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ReflectWatch<T> {
+    pub fn new(target: ReflectTarget) -> Self {
+        Self {
+            target,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Reflect + Clone> Command for ReflectWatch<T> {
+    fn apply(self, world: &mut World) {
+        world
+            .get_resource_or_insert_with(ReflectWatches::<T>::default)
+            .watch(self.target);
+    }
+}
+
+/// System that reads the serialized value of each watched target, and sends a [`ReflectFieldChanged<T>`] event when
+/// it differs from the last snapshot.
+///
+/// Targets that can not be read, e.g. because the entity was despawned, are skipped and keep their last snapshot.
+pub fn reflect_watch_system<T: Reflect + Clone>(world: &mut World) {
+    world.resource_scope(|world, mut watches: Mut<ReflectWatches<T>>| {
+        for (target, snapshot) in watches.snapshots.iter_mut() {
+            let Ok(serialized) = target.read_value_serialized(world) else {
+                continue;
+            };
+            if snapshot
+                .as_ref()
+                .is_some_and(|snapshot| snapshot.serialized == serialized)
+            {
+                continue;
+            }
+            let Ok(value) = target.read_value::<T>(world) else {
+                continue;
+            };
+
+            let previous = snapshot.replace(ReflectWatchSnapshot {
+                serialized,
+                value: value.clone(),
+            });
+            if let Some(previous) = previous {
+                world.send_event(ReflectFieldChanged {
+                    target: target.clone(),
+                    old: previous.value,
+                    new: value,
+                });
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    #[test]
+    fn reflect_watch_sends_changed_event() {
+        let mut app = App::new();
+        app.add_plugins(ReflectWatchPlugin::<i32>::default())
+            .register_type::<ResourceA>()
+            .insert_resource(ResourceA { value: 1 });
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        target.watch::<i32>().apply(app.world_mut());
+        assert!(app
+            .world()
+            .resource::<ReflectWatches<i32>>()
+            .is_watching(&target));
+
+        // The first update only takes a snapshot, no event is sent
+        app.update();
+        let events = app.world().resource::<Events<ReflectFieldChanged<i32>>>();
+        assert!(events.is_empty());
+
+        // Change the value and test we receive an event with the old and new values
+        app.world_mut().resource_mut::<ResourceA>().value = 2;
+        app.update();
+        let events = app.world().resource::<Events<ReflectFieldChanged<i32>>>();
+        let mut reader = events.get_reader();
+        let received: Vec<_> = reader.read(events).collect();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].target, target);
+        assert_eq!(received[0].old, 1);
+        assert_eq!(received[0].new, 2);
+    }
+}
@@ -1,31 +1,125 @@
 use std::any::TypeId;
 
 use bevy::{
+    ecs::{component::Tick, entity::EntityHashMap},
     prelude::*,
     reflect::{
-        serde::{ReflectDeserializer, ReflectSerializer},
-        DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, TypeRegistry, VariantInfo,
+        serde::{
+            ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer,
+            TypedReflectSerializer,
+        },
+        Access, DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, ParsedPath,
+        ReflectFromReflect, ReflectMut, ReflectRef, TypeRegistry, VariantInfo, VariantType,
     },
     scene::ron,
 };
 use serde::de::DeserializeSeed;
 
-use crate::ReflectError;
+use crate::{
+    macros::reflect_span, ReflectError, ReflectPathDiagnostic, ReflectSetResult, ReflectSetSuccess,
+    ResolvedReflectKind, SerializationFormat,
+};
 
 pub fn deserialize_reflect_value(
     world: &mut World,
     serialized_value: &str,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    deserialize_reflect_value_with_format(world, serialized_value, SerializationFormat::Ron)
+}
+
+/// De-serializes a RON value into a `Box<dyn Reflect>` using a `TypeRegistry` directly, for when only readonly world
+/// access is available.
+pub fn deserialize_reflect_value_with_registry(
+    type_registry: &TypeRegistry,
+    serialized_value: &str,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    reflect_span!("deserialize_reflect_value_with_registry");
+    deserialize_ron_with_seed(
+        ReflectDeserializer::new(type_registry),
+        serialized_value.as_bytes(),
+    )
+}
+
+/// De-serializes a bare RON value, without the `{"type::Path": value}` wrapper, as the type with the given type id.
+///
+/// Returns `ReflectError::TypeRegistrationNotFound` if the type is not registered.
+pub fn deserialize_reflect_value_typed(
+    type_registry: &TypeRegistry,
+    type_id: TypeId,
+    serialized_value: &str,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    let registration = type_registry
+        .get(type_id)
+        .ok_or(ReflectError::TypeRegistrationNotFound)?;
+    reflect_span!(
+        "deserialize_reflect_value_typed",
+        type_path = registration.type_info().type_path()
+    );
+    deserialize_ron_with_seed(
+        TypedReflectDeserializer::new(registration, type_registry),
+        serialized_value.as_bytes(),
+    )
+}
+
+/// De-serializes a RON value from a reader into a `Box<dyn Reflect>`.
+///
+/// The RON parser needs the whole input at once, so the reader is read to the end into a byte buffer first. IO errors
+/// are returned as `ReflectError::Deserialize`.
+pub fn deserialize_reflect_value_from_reader(
+    world: &mut World,
+    reader: &mut impl std::io::Read,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|err| ReflectError::Deserialize(format!("{err:?}")))?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    deserialize_ron_with_seed(ReflectDeserializer::new(&type_registry), &bytes)
+}
+
+/// De-serializes RON input with a reflect deserializer seed, reporting the position of any error.
+fn deserialize_ron_with_seed<'de>(
+    seed: impl DeserializeSeed<'de, Value = Box<dyn Reflect>>,
+    input: &'de [u8],
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    let mut deserializer = ron::de::Deserializer::from_bytes(input)?;
+    let value = seed
+        .deserialize(&mut deserializer)
+        .map_err(|err| deserializer.span_error(err))?;
+    Ok(value)
+}
+
+/// De-serializes a value in the specified format into a `Box<dyn Reflect>`.
+pub fn deserialize_reflect_value_with_format(
+    world: &mut World,
+    serialized_value: &str,
+    format: SerializationFormat,
 ) -> Result<Box<dyn Reflect>, ReflectError> {
     let app_type_registry = world.resource_mut::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
+    reflect_span!("deserialize_reflect_value_with_format", ?format);
 
     // De-serialize the value
-    let reflect_deserializer = ReflectDeserializer::new(&type_registry);
-    let mut deserializer = ron::de::Deserializer::from_str(serialized_value)
-        .map_err(|err| ReflectError::Deserialize(format!("{err:?}")))?;
-    reflect_deserializer
-        .deserialize(&mut deserializer)
-        .map_err(|err| ReflectError::Deserialize(format!("{err:?}")))
+    match format {
+        SerializationFormat::Ron => {
+            deserialize_reflect_value_with_registry(&type_registry, serialized_value)
+        }
+        #[cfg(feature = "json")]
+        SerializationFormat::Json => {
+            let reflect_deserializer = ReflectDeserializer::new(&type_registry);
+            let mut deserializer = serde_json::Deserializer::from_str(serialized_value);
+            reflect_deserializer
+                .deserialize(&mut deserializer)
+                .map_err(|err| ReflectError::Deserialize(format!("{err:?}")))
+        }
+        #[cfg(not(feature = "json"))]
+        SerializationFormat::Json => Err(ReflectError::Deserialize(
+            "The `json` feature is not enabled".to_string(),
+        )),
+    }
 }
 
 pub fn serialize_reflect_value(
@@ -34,8 +128,87 @@ pub fn serialize_reflect_value(
 ) -> Result<String, ReflectError> {
     // By default, all derived `Reflect` types can be Serialized using serde. No need to derive
     // Serialize!
+    reflect_span!(
+        "serialize_reflect_value",
+        type_path = value.reflect_type_path()
+    );
+    let serializer = ReflectSerializer::new(value, type_registry);
+    Ok(ron::ser::to_string(&serializer)?)
+}
+
+/// Serializes a value as bare RON, without the `{"type::Path": value}` wrapper, e.g. `5`, `true` or `Red`.
+///
+/// The output can be read back with `deserialize_reflect_value_typed`.
+pub fn serialize_reflect_value_typed(
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<String, ReflectError> {
+    let serializer = TypedReflectSerializer::new(value, type_registry);
+    Ok(ron::ser::to_string(&serializer)?)
+}
+
+/// Serializes a value as RON directly into a writer, without building an intermediate `String`.
+pub fn serialize_reflect_value_to_writer(
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+    writer: &mut impl std::io::Write,
+) -> Result<(), ReflectError> {
+    let serializer = ReflectSerializer::new(value, type_registry);
+    Ok(ron::ser::to_writer(writer, &serializer)?)
+}
+
+/// Serializes a value into a compact binary encoding with `bincode`. Requires the `bincode` feature.
+#[cfg(feature = "bincode")]
+pub fn serialize_reflect_value_bytes(
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<Vec<u8>, ReflectError> {
+    use bincode::Options;
+
+    let serializer = ReflectSerializer::new(value, type_registry);
+    bincode::DefaultOptions::new()
+        .serialize(&serializer)
+        .map_err(|err| ReflectError::Serialize(err.to_string()))
+}
+
+/// De-serializes a value encoded by `serialize_reflect_value_bytes` into a `Box<dyn Reflect>`. Requires the `bincode`
+/// feature.
+#[cfg(feature = "bincode")]
+pub fn deserialize_reflect_value_bytes(
+    type_registry: &TypeRegistry,
+    bytes: &[u8],
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    use bincode::Options;
+
+    let reflect_deserializer = ReflectDeserializer::new(type_registry);
+    bincode::DefaultOptions::new()
+        .deserialize_seed(reflect_deserializer, bytes)
+        .map_err(|err| ReflectError::Deserialize(err.to_string()))
+}
+
+/// Serializes a value into a `serde_json::Value` tree, for manipulating it programmatically without a string
+/// round-trip. Requires the `json` feature.
+#[cfg(feature = "json")]
+pub fn reflect_to_json_value(
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<serde_json::Value, ReflectError> {
     let serializer = ReflectSerializer::new(value, type_registry);
-    ron::ser::to_string(&serializer).map_err(|err| ReflectError::Serialize(format!("{err:?}")))
+    serde_json::to_value(serializer).map_err(|err| ReflectError::Serialize(err.to_string()))
+}
+
+/// De-serializes a `serde_json::Value` tree produced by `reflect_to_json_value` into a `Box<dyn Reflect>`. Requires the
+/// `json` feature.
+#[cfg(feature = "json")]
+pub fn reflect_from_json_value(
+    world: &World,
+    value: &serde_json::Value,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_deserializer = ReflectDeserializer::new(&type_registry);
+    reflect_deserializer
+        .deserialize(value)
+        .map_err(|err| ReflectError::Deserialize(err.to_string()))
 }
 
 pub fn serialize_reflect_value_from_world<T: Reflect>(
@@ -47,15 +220,140 @@ pub fn serialize_reflect_value_from_world<T: Reflect>(
     serialize_reflect_value(&type_registry, value)
 }
 
-/// Returns the default value for a reflectable type id, if it can.
+/// Sets a reflected field to a new value.
+///
+/// Returns `ReflectSetSuccess::NoChanges` without setting the field if it is already equal to the new value.
+pub fn reflect_set_field(field: &mut dyn Reflect, value: Box<dyn Reflect>) -> ReflectSetResult {
+    let is_eq = field.reflect_partial_eq(value.as_reflect());
+    match is_eq {
+        Some(true) => Ok(ReflectSetSuccess::NoChanges),
+        _ => match field.set(value) {
+            Ok(_) => Ok(ReflectSetSuccess::Changed),
+            // NOTE: The error message contained below is not useful, it is usually the name of the dynamic type,
+            // e.g. "DynamicStruct".
+            Err(_) => Err(ReflectError::SetValueFailed),
+        },
+    }
+}
+
+/// Sets a reflected field to a new value, detecting unchanged values for types that don't reflect `PartialEq`.
+///
+/// Works the same as `reflect_set_field`, except when `reflect_partial_eq` returns `None` the old and new values are
+/// serialized and the strings compared instead. Serializing both values is much slower than `reflect_partial_eq`, so
+/// reflect `PartialEq` on types that are set often. If either value can not be serialized, the field is set and
+/// `ReflectSetSuccess::Changed` is returned.
+pub fn reflect_set_field_comparing_serialized(
+    type_registry: &TypeRegistry,
+    field: &mut dyn Reflect,
+    value: Box<dyn Reflect>,
+) -> ReflectSetResult {
+    if field.reflect_partial_eq(value.as_reflect()).is_none() {
+        let old = serialize_reflect_value(type_registry, field);
+        let new = serialize_reflect_value(type_registry, value.as_reflect());
+        if let (Ok(old), Ok(new)) = (old, new) {
+            if old == new {
+                return Ok(ReflectSetSuccess::NoChanges);
+            }
+        }
+    }
+    reflect_set_field(field, value)
+}
+
+/// Returns the default value for a reflectable type id.
+///
+/// Returns `ReflectError::NoDefaultValue` if the type does not reflect `Default`.
 /// CREDIT: Copied from `bevy-inspector-egui`
-fn get_default_value_for(
+pub fn reflect_default_value(
     type_registry: &TypeRegistry,
     type_id: TypeId,
-) -> Option<Box<dyn Reflect>> {
+) -> Result<Box<dyn Reflect>, ReflectError> {
     type_registry
         .get_type_data::<ReflectDefault>(type_id)
         .map(|reflect_default| reflect_default.default())
+        .ok_or(ReflectError::NoDefaultValue)
+}
+
+/// Returns a concrete copy of a value, built with its reflected `FromReflect`, or with its reflected `Default` and the
+/// value applied on top.
+///
+/// Unlike `clone_value`, which returns a dynamic copy of structs and enums, applying to the copy fails for values that
+/// don't fit the concrete type, such as an enum variant with the wrong shape. Returns `ReflectError::NoDefaultValue` if
+/// the type reflects neither.
+pub(crate) fn reflect_concrete_clone(
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<Box<dyn Reflect>, ReflectError> {
+    let type_id = value
+        .get_represented_type_info()
+        .ok_or(ReflectError::TypeRegistrationNotFound)?
+        .type_id();
+    if let Some(reflect_from_reflect) = type_registry.get_type_data::<ReflectFromReflect>(type_id) {
+        return reflect_from_reflect
+            .from_reflect(value)
+            .ok_or(ReflectError::SetValueFailed);
+    }
+    let mut copy = reflect_default_value(type_registry, type_id)?;
+    copy.try_apply(value)
+        .map_err(|_| ReflectError::SetValueFailed)?;
+    Ok(copy)
+}
+
+/// Returns the `ReflectResource` type data for a type id.
+///
+/// Returns `ReflectError::TypeDataMismatch` if the type is only registered as a component, or
+/// `ReflectError::TypeRegistrationInvalidCast` if it is registered as neither.
+pub fn get_reflect_resource(
+    type_registry: &TypeRegistry,
+    type_id: TypeId,
+) -> Result<&ReflectResource, ReflectError> {
+    let registration = type_registry
+        .get(type_id)
+        .ok_or(ReflectError::TypeRegistrationNotFound)?;
+    registration.data::<ReflectResource>().ok_or_else(|| {
+        if registration.data::<ReflectComponent>().is_some() {
+            ReflectError::TypeDataMismatch {
+                expected: "ReflectResource",
+                found: "ReflectComponent",
+            }
+        } else {
+            ReflectError::TypeRegistrationInvalidCast
+        }
+    })
+}
+
+/// Returns the `ReflectComponent` type data for a type id.
+///
+/// Returns `ReflectError::TypeDataMismatch` if the type is only registered as a resource, or
+/// `ReflectError::TypeRegistrationInvalidCast` if it is registered as neither.
+pub fn get_reflect_component(
+    type_registry: &TypeRegistry,
+    type_id: TypeId,
+) -> Result<&ReflectComponent, ReflectError> {
+    let registration = type_registry
+        .get(type_id)
+        .ok_or(ReflectError::TypeRegistrationNotFound)?;
+    registration.data::<ReflectComponent>().ok_or_else(|| {
+        if registration.data::<ReflectResource>().is_some() {
+            ReflectError::TypeDataMismatch {
+                expected: "ReflectComponent",
+                found: "ReflectResource",
+            }
+        } else {
+            ReflectError::TypeRegistrationInvalidCast
+        }
+    })
+}
+
+/// Returns the serialized default value for a reflectable type id.
+///
+/// Returns `ReflectError::NoDefaultValue` if the type does not reflect `Default`.
+pub fn reflect_default_value_serialized(
+    world: &World,
+    type_id: TypeId,
+) -> Result<String, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let value = reflect_default_value(&type_registry, type_id)?;
+    serialize_reflect_value(&type_registry, value.as_reflect())
 }
 
 /// Utility that constructs `DynamicEnum` with the default value for the variant.
@@ -68,8 +366,7 @@ pub fn construct_default_enum_variant(
         VariantInfo::Struct(struct_info) => {
             let mut dynamic_struct = DynamicStruct::default();
             for field in struct_info.iter() {
-                let field_default_value = get_default_value_for(type_registry, field.type_id())
-                    .ok_or(ReflectError::NoDefaultValue)?;
+                let field_default_value = reflect_default_value(type_registry, field.type_id())?;
                 dynamic_struct.insert_boxed(field.name(), field_default_value);
             }
             DynamicVariant::Struct(dynamic_struct)
@@ -77,8 +374,7 @@ pub fn construct_default_enum_variant(
         VariantInfo::Tuple(tuple_info) => {
             let mut dynamic_tuple = DynamicTuple::default();
             for field in tuple_info.iter() {
-                let field_default_value = get_default_value_for(type_registry, field.type_id())
-                    .ok_or(ReflectError::NoDefaultValue)?;
+                let field_default_value = reflect_default_value(type_registry, field.type_id())?;
                 dynamic_tuple.insert_boxed(field_default_value);
             }
             DynamicVariant::Tuple(dynamic_tuple)
@@ -90,12 +386,464 @@ pub fn construct_default_enum_variant(
     Ok(dynamic_enum)
 }
 
+/// Checks the tuple and list indices in a path against the number of fields or elements of the value they index into.
+///
+/// Returns a `ReflectError::ReflectPath` describing the first index that is out of range, e.g.
+/// `"index 3 out of range for tuple of arity 2"`, or the first field name used on a tuple struct. Returns `Ok(())` if
+/// every index is in range, or if the path can not be resolved for any other reason.
+pub fn validate_path_indices(root: &dyn Reflect, path: &str) -> Result<(), ReflectError> {
+    let Ok(parsed_path) = ParsedPath::parse(path) else {
+        return Ok(());
+    };
+
+    let mut current = root;
+    for offset_access in parsed_path.0.iter() {
+        if let (Access::Field(name), ReflectRef::TupleStruct(_)) =
+            (&offset_access.access, current.reflect_ref())
+        {
+            return Err(ReflectError::ReflectPath(format!(
+                "`{}` is a tuple struct, use a numeric index like \"0\" instead of the field name \"{name}\"",
+                current.reflect_short_type_path()
+            )));
+        }
+
+        let out_of_range = match (&offset_access.access, current.reflect_ref()) {
+            (Access::TupleIndex(index), ReflectRef::Tuple(value)) => {
+                (*index >= value.field_len()).then(|| (*index, "tuple of arity", value.field_len()))
+            }
+            (Access::TupleIndex(index), ReflectRef::TupleStruct(value)) => {
+                (*index >= value.field_len()).then(|| (*index, "tuple of arity", value.field_len()))
+            }
+            (Access::TupleIndex(index), ReflectRef::Enum(value))
+                if value.variant_type() == VariantType::Tuple =>
+            {
+                (*index >= value.field_len()).then(|| (*index, "tuple of arity", value.field_len()))
+            }
+            (Access::ListIndex(index), ReflectRef::Array(value)) => {
+                (*index >= value.len()).then(|| (*index, "array of length", value.len()))
+            }
+            (Access::ListIndex(index), ReflectRef::List(value)) => {
+                (*index >= value.len()).then(|| (*index, "list of length", value.len()))
+            }
+            _ => None,
+        };
+        if let Some((index, description, len)) = out_of_range {
+            return Err(ReflectError::ReflectPath(format!(
+                "index {index} out of range for {description} {len}"
+            )));
+        }
+
+        match current.reflect_path(&ParsedPath(vec![offset_access.clone()])) {
+            Ok(next) => current = next,
+            Err(_) => return Ok(()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Recursively replaces every `Entity` in a reflected value with its mapped entity in `entity_map`.
+///
+/// Entities that are not in the map are left unchanged.
+pub fn reflect_map_entities(value: &mut dyn Reflect, entity_map: &EntityHashMap<Entity>) {
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    reflect_map_entities(field, entity_map);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    reflect_map_entities(field, entity_map);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    reflect_map_entities(field, entity_map);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    reflect_map_entities(item, entity_map);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    reflect_map_entities(item, entity_map);
+                }
+            }
+        }
+        ReflectMut::Map(value) => {
+            for index in 0..value.len() {
+                if let Some((_, item)) = value.get_at_mut(index) {
+                    reflect_map_entities(item, entity_map);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    reflect_map_entities(field, entity_map);
+                }
+            }
+        }
+        ReflectMut::Value(value) => {
+            if let Some(entity) = value.downcast_mut::<Entity>() {
+                if let Some(mapped_entity) = entity_map.get(entity) {
+                    *entity = *mapped_entity;
+                }
+            }
+        }
+    }
+}
+
+/// Compares two values field-by-field, skipping the fields in `ignore_paths`.
+///
+/// Ignored fields are given by their path from the root, e.g. `"timestamp"` or `"inner.cache"`, and skip every field
+/// nested inside them. The values are equal if `reflect_diff_paths` finds no differing leaf fields, and neither value
+/// has a leaf field the other is missing.
+///
+/// Values are compared by the type they represent, so a `DynamicStruct` clone of a value compares equal to it, and
+/// dynamic values without a represented type are compared structurally.
+pub fn reflect_partial_eq_ignoring(
+    type_registry: &TypeRegistry,
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+    ignore_paths: &[&str],
+) -> Result<bool, ReflectError> {
+    if !reflect_diff_paths_at(type_registry, a, b, ignore_paths, true)?.is_empty() {
+        return Ok(false);
+    }
+
+    // The diff only walks the leaf fields of `b`, so also check `b` has every leaf field of `a`
+    let mut has_all_fields = true;
+    visit_leaf_fields(a, "", &mut |path, _| -> Result<(), ReflectError> {
+        has_all_fields &= is_ignored_path(path, ignore_paths) || b.reflect_path(path).is_ok();
+        Ok(())
+    })?;
+    Ok(has_all_fields)
+}
+
+/// Returns the paths of the leaf fields that differ between two values, e.g. `["inner.value"]`.
+///
+/// The leaf fields of `new` are compared with the fields at the same paths in `old`, so `new` may be a `DynamicStruct`
+/// patch for `old`'s type, and fields missing from `new` are skipped. Leaf fields are compared with
+/// `reflect_partial_eq`, falling back to comparing their serialized values for types that don't support it. If the
+/// values represent different types, or are not structs and differ, the returned path is empty, meaning the whole value.
+pub fn reflect_diff_paths(
+    type_registry: &TypeRegistry,
+    old: &dyn Reflect,
+    new: &dyn Reflect,
+) -> Result<Vec<String>, ReflectError> {
+    reflect_diff_paths_at(type_registry, old, new, &[], false)
+}
+
+/// Returns the paths of the leaf fields of `new` that differ from `old`, skipping the fields in `ignore_paths`. Leaf
+/// fields missing from `old` are only reported if `report_missing` is set.
+fn reflect_diff_paths_at(
+    type_registry: &TypeRegistry,
+    old: &dyn Reflect,
+    new: &dyn Reflect,
+    ignore_paths: &[&str],
+    report_missing: bool,
+) -> Result<Vec<String>, ReflectError> {
+    if !is_same_represented_type(old, new) {
+        return Ok(vec![String::new()]);
+    }
+
+    let mut paths = Vec::new();
+    visit_leaf_fields(new, "", &mut |path,
+                                     new_field|
+     -> Result<(), ReflectError> {
+        if is_ignored_path(path, ignore_paths) {
+            return Ok(());
+        }
+        let is_eq = match old.reflect_path(path) {
+            Ok(old_field) => reflect_leaf_eq(type_registry, old_field, new_field)?,
+            Err(_) => !report_missing,
+        };
+        if !is_eq {
+            paths.push(path.to_string());
+        }
+        Ok(())
+    })?;
+    Ok(paths)
+}
+
+/// Compares two leaf values with `reflect_partial_eq`, falling back to comparing their serialized values.
+fn reflect_leaf_eq(
+    type_registry: &TypeRegistry,
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+) -> Result<bool, ReflectError> {
+    if !is_same_represented_type(a, b) {
+        return Ok(false);
+    }
+    match a.reflect_partial_eq(b) {
+        Some(result) => Ok(result),
+        None => Ok(serialize_reflect_value(type_registry, a)?
+            == serialize_reflect_value(type_registry, b)?),
+    }
+}
+
+/// Whether two values represent the same type. Dynamic values without a represented type match any type.
+pub(crate) fn is_same_represented_type(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+    match (represented_type_path(a), represented_type_path(b)) {
+        (Some(type_path_a), Some(type_path_b)) => type_path_a == type_path_b,
+        _ => true,
+    }
+}
+
+/// Whether `path` is one of `ignore_paths`, or a field nested inside one of them.
+fn is_ignored_path(path: &str, ignore_paths: &[&str]) -> bool {
+    ignore_paths.iter().any(|ignored| {
+        path.strip_prefix(ignored)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+/// Merges two serialized struct patches of the same type, with the fields of `overlay` taking precedence over `base`,
+/// e.g. to layer user overrides on top of default settings. Returns the serialized merged patch. See
+/// `merge_reflect_patches`.
+pub fn merge_serialized_patches(
+    world: &World,
+    base: &str,
+    overlay: &str,
+) -> Result<String, ReflectError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let base = deserialize_reflect_value_with_registry(&type_registry, base)?;
+    let overlay = deserialize_reflect_value_with_registry(&type_registry, overlay)?;
+    let merged = merge_reflect_patches(base.as_reflect(), overlay.as_reflect())?;
+    serialize_reflect_value(&type_registry, merged.as_reflect())
+}
+
+/// Merges two struct patches of the same type, such as `DynamicStruct`s, with the fields of `overlay` taking precedence
+/// over `base`.
+///
+/// Struct and tuple struct fields are merged recursively, and every leaf field of `overlay` replaces the one in `base`,
+/// or is inserted if `base` does not have it. Returns `ReflectError::NotAStruct` if either patch is not a struct,
+/// `ReflectError::InvalidDowncast` if the patches represent different types, or `ReflectError::SetValueFailed` if a
+/// tuple struct field of `overlay` does not match the type of the same field in `base`.
+pub fn merge_reflect_patches(
+    base: &dyn Reflect,
+    overlay: &dyn Reflect,
+) -> Result<DynamicStruct, ReflectError> {
+    let (ReflectRef::Struct(base_struct), ReflectRef::Struct(_)) =
+        (base.reflect_ref(), overlay.reflect_ref())
+    else {
+        return Err(ReflectError::NotAStruct);
+    };
+    let base_type_path = represented_type_path(base);
+    if base_type_path.is_none() || base_type_path != represented_type_path(overlay) {
+        return Err(ReflectError::InvalidDowncast);
+    }
+
+    let mut merged = base_struct.clone_dynamic();
+    visit_leaf_fields(overlay, "", &mut |path, _| -> Result<(), ReflectError> {
+        // Replace the outermost field along the path that `merged` does not have yet, or the leaf itself
+        let replace_path = path
+            .match_indices('.')
+            .map(|(index, _)| &path[..index])
+            .find(|prefix| merged.reflect_path(*prefix).is_err())
+            .unwrap_or(path);
+        let overlay_field = overlay
+            .reflect_path(replace_path)
+            .map_err(|err| reflect_path_error(overlay, replace_path, err))?;
+        replace_dynamic_field(&mut merged, replace_path, overlay_field.clone_value())
+    })?;
+    Ok(merged)
+}
+
+/// Replaces the field at `path` in a merged patch, inserting it if its parent struct does not have it yet.
+///
+/// Returns `ReflectError::ReflectPath` if the parent or tuple struct field does not exist, or
+/// `ReflectError::SetValueFailed` if the value does not match the type of a tuple struct field.
+fn replace_dynamic_field(
+    merged: &mut DynamicStruct,
+    path: &str,
+    value: Box<dyn Reflect>,
+) -> Result<(), ReflectError> {
+    let (parent_path, name) = path.rsplit_once('.').unwrap_or(("", path));
+    if let Err(err) = merged.reflect_path(parent_path) {
+        return Err(reflect_path_error(merged.as_reflect(), parent_path, err));
+    }
+    let parent = merged
+        .reflect_path_mut(parent_path)
+        .map_err(|err| ReflectError::ReflectPath(err.to_string()))?;
+
+    if let Some(parent_struct) = parent.downcast_mut::<DynamicStruct>() {
+        parent_struct.insert_boxed(name, value);
+        return Ok(());
+    }
+    let field = match (parent.reflect_mut(), name.parse::<usize>()) {
+        (ReflectMut::TupleStruct(tuple_struct), Ok(index)) => tuple_struct.field_mut(index),
+        _ => None,
+    }
+    .ok_or_else(|| ReflectError::ReflectPath(format!("The merged patch has no field `{path}`")))?;
+    if let Err(value) = field.set(value) {
+        field
+            .try_apply(value.as_reflect())
+            .map_err(|_| ReflectError::SetValueFailed)?;
+    }
+    Ok(())
+}
+
+/// Calls `visit_fn` with the path and value of every leaf field of `value`, walking struct and tuple struct fields
+/// recursively like the form schema does. Stops at the first error returned by `visit_fn`.
+///
+/// Shared by the diff, path search and patch merging helpers, so they all agree on which fields are leaves and how
+/// their paths are written.
+pub(crate) fn visit_leaf_fields<E>(
+    value: &dyn Reflect,
+    path: &str,
+    visit_fn: &mut impl FnMut(&str, &dyn Reflect) -> Result<(), E>,
+) -> Result<(), E> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(dyn_struct) => {
+            for (index, field) in dyn_struct.iter_fields().enumerate() {
+                if let Some(name) = dyn_struct.name_at(index) {
+                    visit_leaf_fields(field, &join_field_path(path, name), visit_fn)?;
+                }
+            }
+            Ok(())
+        }
+        ReflectRef::TupleStruct(tuple_struct) => {
+            for (index, field) in tuple_struct.iter_fields().enumerate() {
+                visit_leaf_fields(field, &join_field_path(path, &index.to_string()), visit_fn)?;
+            }
+            Ok(())
+        }
+        _ => visit_fn(path, value),
+    }
+}
+
+/// Returns the tick the component or resource was last changed.
+///
+/// Returns `ReflectError::EntityNotFound`, `ReflectError::EntityDoesNotHaveComponent` or
+/// `ReflectError::ResourceDoesNotExist` if it does not exist.
+pub(crate) fn last_changed_tick(
+    world: &World,
+    kind: ResolvedReflectKind,
+) -> Result<Tick, ReflectError> {
+    match kind {
+        ResolvedReflectKind::Component(entity, type_id) => {
+            let entity_ref = world
+                .get_entity(entity)
+                .ok_or(ReflectError::EntityNotFound)?;
+            world
+                .components()
+                .get_id(type_id)
+                .and_then(|component_id| entity_ref.get_change_ticks_by_id(component_id))
+                .map(|ticks| ticks.last_changed_tick())
+                .ok_or(ReflectError::EntityDoesNotHaveComponent)
+        }
+        ResolvedReflectKind::Resource(type_id) => world
+            .components()
+            .get_resource_id(type_id)
+            .and_then(|component_id| world.get_resource_change_ticks_by_id(component_id))
+            .map(|ticks| ticks.last_changed_tick())
+            .ok_or(ReflectError::ResourceDoesNotExist),
+    }
+}
+
+/// Appends a field name or tuple index to a path, e.g. `"inner"` and `"value"` become `"inner.value"`.
+pub(crate) fn join_field_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{path}.{segment}")
+    }
+}
+
+pub(crate) fn represented_type_path(value: &dyn Reflect) -> Option<&'static str> {
+    value
+        .get_represented_type_info()
+        .map(|type_info| type_info.type_path())
+}
+
+/// Walks a path one segment at a time from `root`, reporting the deepest segment that resolved and the one that failed.
+///
+/// Returns `Ok(())` if the whole path resolves.
+pub fn diagnose_reflect_path(root: &dyn Reflect, path: &str) -> Result<(), ReflectPathDiagnostic> {
+    let parsed_path = ParsedPath::parse(path).map_err(|err| ReflectPathDiagnostic {
+        resolved: String::new(),
+        failed_segment: String::new(),
+        type_path: root.reflect_type_path().to_string(),
+        error: err.to_string(),
+    })?;
+
+    let mut resolved = String::new();
+    let mut current = root;
+    for offset_access in parsed_path.0.iter() {
+        match current.reflect_path(&ParsedPath(vec![offset_access.clone()])) {
+            Ok(next) => {
+                resolved.push_str(&offset_access.access.to_string());
+                current = next;
+            }
+            Err(err) => {
+                return Err(ReflectPathDiagnostic {
+                    resolved,
+                    failed_segment: offset_access.access.to_string(),
+                    type_path: current.reflect_type_path().to_string(),
+                    error: err.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts an error returned by `reflect_path` into a `ReflectError`.
+///
+/// The path is only walked again when resolving it failed, so the happy path pays nothing for the more descriptive
+/// out-of-range messages produced by `validate_path_indices`.
+pub(crate) fn reflect_path_error(
+    root: &dyn Reflect,
+    path: &str,
+    err: impl std::fmt::Display,
+) -> ReflectError {
+    match validate_path_indices(root, path) {
+        Err(index_err) => index_err,
+        Ok(()) => ReflectError::ReflectPath(err.to_string()),
+    }
+}
+
 /// Utility that tries to read the `TypeId` of a type path from a `TypeRegistry`.
 ///
+/// Types are looked up by their full type path, then by their short type path if no other registered type shares it.
+/// Generic type paths like `my_crate::Foo<my_crate::Bar, u32>` must be fully qualified to be found by the slower
+/// fallback, which matches them ignoring whitespace, so `my_crate::Foo<my_crate::Bar,u32>` resolves to the same type.
+///
 /// Returns None if the type is not registered.
 pub fn get_type_id_for_type_path(type_registry: &TypeRegistry, type_path: &str) -> Option<TypeId> {
-    type_registry
+    if let Some(type_registration) = type_registry
         .get_with_type_path(type_path)
+        .or_else(|| type_registry.get_with_short_type_path(type_path))
+    {
+        return Some(type_registration.type_id());
+    }
+    if !type_path.contains('<') {
+        return None;
+    }
+
+    // Fall back to comparing generic type paths without whitespace, which the registry lookup is sensitive to
+    let without_whitespace = |path: &'static str| path.chars().filter(|c| !c.is_whitespace());
+    type_registry
+        .iter()
+        .find(|type_registration| {
+            without_whitespace(type_registration.type_info().type_path())
+                .eq(type_path.chars().filter(|c| !c.is_whitespace()))
+        })
         .map(|type_registration| type_registration.type_id())
 }
 
@@ -111,6 +859,8 @@ pub fn get_type_id_for_type_path_from_world(world: &World, type_path: &str) -> O
 
 #[cfg(test)]
 mod tests {
+    use bevy::reflect::{DynamicTupleStruct, Typed};
+
     use super::*;
 
     #[derive(Resource, Reflect, Default, Clone)]
@@ -125,6 +875,33 @@ mod tests {
     #[reflect(Component)]
     struct NonRegisteredComponent;
 
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Default)]
+    struct StructA {
+        value: u32,
+    }
+
+    impl Default for StructA {
+        fn default() -> Self {
+            Self { value: 5 }
+        }
+    }
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    enum EnumA {
+        A(u32),
+        #[default]
+        B,
+    }
+
+    #[derive(Reflect, Default, Clone)]
+    struct ConfigA {
+        value: u32,
+        timestamp: f32,
+        inner: StructA,
+    }
+
     /// Test utility that creates a new world and registers the test types
     fn create_world() -> World {
         let mut world = World::new();
@@ -133,10 +910,268 @@ mod tests {
         let type_registry = world.resource_mut::<AppTypeRegistry>();
         type_registry.write().register::<ResourceA>();
         type_registry.write().register::<ComponentA>();
+        type_registry.write().register::<StructA>();
+        type_registry.write().register::<EnumA>();
+        type_registry.write().register::<ConfigA>();
 
         world
     }
 
+    #[test]
+    fn deserialize_reflect_value_error_includes_position() {
+        let mut world = create_world();
+
+        let result = deserialize_reflect_value(&mut world, "{\n    \"u32\":\n    nope\n}");
+        let Err(ReflectError::Deserialize(message)) = result else {
+            panic!("Expected a de-serialization error, got {result:?}");
+        };
+        // The error is on the third line
+        assert!(message.starts_with("3:"), "{message}");
+    }
+
+    #[test]
+    fn reflect_partial_eq_ignoring_works() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        let a = ConfigA {
+            value: 1,
+            timestamp: 1.,
+            inner: StructA { value: 2 },
+        };
+        let mut b = a.clone();
+        b.timestamp = 2.;
+
+        // Test values that only differ in an ignored field compare equal
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &a, &b, &["timestamp"]),
+            Ok(true)
+        );
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &a, &b, &[]),
+            Ok(false)
+        );
+
+        // Test ignoring a nested field
+        b.inner.value = 3;
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &a, &b, &["timestamp"]),
+            Ok(false)
+        );
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &a, &b, &["timestamp", "inner.value"]),
+            Ok(true)
+        );
+
+        // Test a dynamic clone compares by its represented type
+        let dynamic_b = b.clone_dynamic();
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &b, &dynamic_b, &[]),
+            Ok(true)
+        );
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &a, &dynamic_b, &["timestamp"]),
+            Ok(false)
+        );
+
+        // Test a dynamic struct without a represented type is compared structurally
+        let mut structural = DynamicStruct::default();
+        structural.insert("value", 2_u32);
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &StructA { value: 2 }, &structural, &[]),
+            Ok(true)
+        );
+        structural.insert("extra", 1_u32);
+        assert_eq!(
+            reflect_partial_eq_ignoring(&type_registry, &StructA { value: 2 }, &structural, &[]),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn deserialize_reflect_value_from_reader_works() {
+        let mut world = create_world();
+
+        let mut reader = std::io::Cursor::new(
+            b"{\"bevy_reflect_utils::shared::tests::StructA\":(value:3)}".as_slice(),
+        );
+        let value = deserialize_reflect_value_from_reader(&mut world, &mut reader).unwrap();
+        assert_eq!(
+            StructA::from_reflect(value.as_reflect()),
+            Some(StructA { value: 3 })
+        );
+
+        // Test invalid input returns a de-serialization error
+        let mut reader = std::io::Cursor::new(b"{\"u32\":".as_slice());
+        assert!(matches!(
+            deserialize_reflect_value_from_reader(&mut world, &mut reader),
+            Err(ReflectError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn reflect_default_value_works() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        // Test we can construct the default value of a struct
+        let value = reflect_default_value(&type_registry, TypeId::of::<StructA>()).unwrap();
+        assert_eq!(value.downcast_ref::<StructA>(), Some(&StructA { value: 5 }));
+
+        // Test we can construct the default value of a primitive
+        let value = reflect_default_value(&type_registry, TypeId::of::<u32>()).unwrap();
+        assert_eq!(value.downcast_ref::<u32>(), Some(&0));
+
+        // Test the error when the type does not reflect `Default`
+        let result = reflect_default_value(&type_registry, TypeId::of::<ResourceA>());
+        assert!(matches!(result, Err(ReflectError::NoDefaultValue)));
+    }
+
+    #[test]
+    fn reflect_default_value_serialized_works() {
+        let world = create_world();
+
+        let serialized = reflect_default_value_serialized(&world, TypeId::of::<EnumA>()).unwrap();
+        assert_eq!(
+            serialized,
+            "{\"bevy_reflect_utils::shared::tests::EnumA\":B}"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_value_round_trip_works() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        let json_value = reflect_to_json_value(&type_registry, &StructA { value: 3 }).unwrap();
+        assert_eq!(
+            json_value,
+            serde_json::json!({ "bevy_reflect_utils::shared::tests::StructA": { "value": 3 } })
+        );
+
+        let value = reflect_from_json_value(&world, &json_value).unwrap();
+        assert_eq!(
+            StructA::from_reflect(value.as_reflect()),
+            Some(StructA { value: 3 })
+        );
+    }
+
+    #[test]
+    fn merge_serialized_patches_works() {
+        let world = create_world();
+
+        // Test merging two partial patches, including a nested struct
+        let merged = merge_serialized_patches(
+            &world,
+            "{\"bevy_reflect_utils::shared::tests::ConfigA\":(value:1,inner:(value:2))}",
+            "{\"bevy_reflect_utils::shared::tests::ConfigA\":(timestamp:0.5,inner:(value:3))}",
+        )
+        .unwrap();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let merged = deserialize_reflect_value_with_registry(&type_registry, &merged).unwrap();
+        let config = ConfigA::from_reflect(merged.as_reflect()).unwrap();
+        assert_eq!(config.value, 1);
+        assert_eq!(config.timestamp, 0.5);
+        assert_eq!(config.inner, StructA { value: 3 });
+
+        // Test a nested struct missing from the base patch is inserted
+        let merged = merge_serialized_patches(
+            &world,
+            "{\"bevy_reflect_utils::shared::tests::ConfigA\":(value:1,timestamp:0.5)}",
+            "{\"bevy_reflect_utils::shared::tests::ConfigA\":(inner:(value:4))}",
+        )
+        .unwrap();
+        let merged = deserialize_reflect_value_with_registry(&type_registry, &merged).unwrap();
+        let config = ConfigA::from_reflect(merged.as_reflect()).unwrap();
+        assert_eq!(config.value, 1);
+        assert_eq!(config.inner, StructA { value: 4 });
+
+        // Test the error when the patches describe different types
+        assert_eq!(
+            merge_serialized_patches(
+                &world,
+                "{\"bevy_reflect_utils::shared::tests::ConfigA\":(value:1)}",
+                "{\"bevy_reflect_utils::shared::tests::StructA\":(value:1)}",
+            ),
+            Err(ReflectError::InvalidDowncast)
+        );
+    }
+
+    #[test]
+    fn merge_reflect_patches_errors_on_mismatched_tuple_field() {
+        #[derive(Reflect, Default)]
+        struct Scale(u32);
+
+        #[derive(Reflect, Default)]
+        struct ConfigB {
+            scale: Scale,
+        }
+
+        let base = ConfigB::default().clone_dynamic();
+        let mut scale = DynamicTupleStruct::default();
+        scale.set_represented_type(Some(Scale::type_info()));
+        scale.insert("text".to_string());
+        let mut overlay = DynamicStruct::default();
+        overlay.set_represented_type(Some(ConfigB::type_info()));
+        overlay.insert("scale", scale);
+
+        // Test the overlay field is reported instead of being dropped from the merged patch
+        assert_eq!(
+            merge_reflect_patches(&base, &overlay).map(|_| ()),
+            Err(ReflectError::SetValueFailed)
+        );
+    }
+
+    #[test]
+    fn get_reflect_component_works() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        assert!(get_reflect_component(&type_registry, TypeId::of::<ComponentA>()).is_ok());
+
+        // Test the error when the type is not registered
+        assert!(matches!(
+            get_reflect_component(&type_registry, TypeId::of::<NonRegisteredComponent>()),
+            Err(ReflectError::TypeRegistrationNotFound)
+        ));
+
+        // Test the error when the type is registered without `ReflectComponent`
+        assert!(matches!(
+            get_reflect_component(&type_registry, TypeId::of::<StructA>()),
+            Err(ReflectError::TypeRegistrationInvalidCast)
+        ));
+    }
+
+    #[test]
+    fn get_reflect_resource_works() {
+        let world = create_world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        assert!(get_reflect_resource(&type_registry, TypeId::of::<ResourceA>()).is_ok());
+
+        // Test the error when the type is not registered
+        assert!(matches!(
+            get_reflect_resource(&type_registry, TypeId::of::<NonRegisteredComponent>()),
+            Err(ReflectError::TypeRegistrationNotFound)
+        ));
+
+        // Test the error when the type is registered without `ReflectResource`
+        assert!(matches!(
+            get_reflect_resource(&type_registry, TypeId::of::<StructA>()),
+            Err(ReflectError::TypeRegistrationInvalidCast)
+        ));
+
+        // Test the error when the type is only registered as a component
+        assert!(matches!(
+            get_reflect_resource(&type_registry, TypeId::of::<ComponentA>()),
+            Err(ReflectError::TypeDataMismatch {
+                expected: "ReflectResource",
+                found: "ReflectComponent"
+            })
+        ));
+    }
+
     #[test]
     fn get_type_id_for_type_path_from_world_works() {
         let world = create_world();
@@ -182,6 +1217,12 @@ mod tests {
             ),
             Some(TypeId::of::<ComponentA>())
         );
+
+        // Test we can read the `TypeId` of `ResourceA` by its short type path
+        assert_eq!(
+            get_type_id_for_type_path(&type_registry, "ResourceA"),
+            Some(TypeId::of::<ResourceA>())
+        );
     }
 
     #[test]
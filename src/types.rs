@@ -1,4 +1,7 @@
 use core::fmt;
+use std::any::TypeId;
+
+use bevy::scene::ron;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ReflectSetSuccess {
@@ -8,6 +11,86 @@ pub enum ReflectSetSuccess {
 
 pub type ReflectSetResult = Result<ReflectSetSuccess, ReflectError>;
 
+/// Detailed result of setting a value, containing the serialized values before and after the operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectSetSuccessVerbose {
+    /// Whether the value was changed.
+    pub changed: bool,
+    /// The serialized value before the operation.
+    pub old: String,
+    /// The serialized value after the operation.
+    pub new: String,
+}
+
+/// Result of `ReflectTarget::set_if_different_serialized`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReflectSetIfDifferentSuccess {
+    /// The result of setting the value.
+    pub success: ReflectSetSuccess,
+}
+
+impl ReflectSetIfDifferentSuccess {
+    /// Whether the field already matched the value before it was set.
+    pub fn already_matched(&self) -> bool {
+        self.success == ReflectSetSuccess::NoChanges
+    }
+}
+
+/// Describes where resolving a path failed, returned by `ReflectTarget::diagnose_path`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectPathDiagnostic {
+    /// The longest prefix of the path that resolved, e.g. `".value2.0"`. Empty if the first segment failed.
+    pub resolved: String,
+    /// The segment that failed to resolve, e.g. `".nonexistent"`. Empty if the path could not be parsed, or the target
+    /// itself could not be reached.
+    pub failed_segment: String,
+    /// The type path of the value the failed segment was applied to.
+    pub type_path: String,
+    /// Description of the error.
+    pub error: String,
+}
+
+/// The reflect kind of a field, returned by `ReflectTarget::field_reflect_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Struct,
+    TupleStruct,
+    Tuple,
+    List,
+    Array,
+    Map,
+    Enum,
+    /// An opaque value, such as a primitive or a `String`, with the type ID of the value.
+    Value(TypeId),
+}
+
+impl FieldKind {
+    /// Whether the field is an enum.
+    pub fn is_enum(&self) -> bool {
+        matches!(self, FieldKind::Enum)
+    }
+
+    /// Whether the field is a numeric primitive. See `reflect_number_as_f64` for the supported types.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, FieldKind::Value(type_id) if crate::is_reflect_number_type(*type_id))
+    }
+
+    /// Whether the field is a `bool`.
+    pub fn is_bool(&self) -> bool {
+        *self == FieldKind::Value(TypeId::of::<bool>())
+    }
+}
+
+/// Formats that serialized values can be read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SerializationFormat {
+    /// Rusty Object Notation, the format used by bevy scenes.
+    #[default]
+    Ron,
+    /// JSON. Requires the `json` feature, otherwise reading a value returns `ReflectError::Deserialize`.
+    Json,
+}
+
 /// Error variants for the `bevy_reflect_utils` crate.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReflectError {
@@ -38,6 +121,43 @@ pub enum ReflectError {
     Deserialize(String),
     /// Reflect PartialEq Failed
     PartialEq,
+    /// More than one component on the entity reflects the trait, when exactly one was expected.
+    AmbiguousTrait,
+    /// The field is not a supported numeric type.
+    UnsupportedNumericType,
+    /// The value is not a struct.
+    NotAStruct,
+    /// The enum does not have a variant with the given name.
+    VariantNotFound(String),
+    /// Access was denied, either because the `AppTypeRegistry` lock is held elsewhere or was poisoned, or because a
+    /// `FilteredEntityRef` does not have read access to a component the entity has.
+    ///
+    /// Functions taking `&World`, `EntityRef` or `EntityWorldMut` always have access to every component, so they
+    /// return `EntityDoesNotHaveComponent` rather than this variant.
+    NoAccess,
+    /// A numeric value could not be converted to the field's numeric type without overflowing or losing precision.
+    NumericOutOfRange,
+    /// The number of values does not match the length of the array field.
+    ArrayLengthMismatch { expected: usize, actual: usize },
+    /// More than one entity matched, when exactly one was expected.
+    AmbiguousEntity,
+    /// The type registration does not have the expected type data, but has the type data of the other kind, e.g. a
+    /// component type used as a resource target.
+    TypeDataMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The value is not a map.
+    NotAMap,
+    /// The map does not have an entry for the given serialized key.
+    MapKeyNotFound(String),
+    /// The value was rolled back because the validation closure rejected it.
+    ValidationFailed,
+    /// The validation closure rejected the value, but restoring the previous value failed with the given error, so the
+    /// rejected value is still in the world.
+    RollbackFailed(Box<ReflectError>),
+    /// The active variant of the enum does not have a field with the given name.
+    VariantFieldNotFound { variant: String, field: String },
 }
 
 impl fmt::Display for ReflectError {
@@ -60,6 +180,59 @@ impl fmt::Display for ReflectError {
             ReflectError::Serialize(err) => write!(f, "Serialization failed: {err}"),
             ReflectError::Deserialize(err) => write!(f, "De-serialization failed: {err}"),
             ReflectError::PartialEq => write!(f, "Reflect PartialEq failed"),
+            ReflectError::AmbiguousTrait => {
+                write!(
+                    f,
+                    "More than one component on the entity reflects the trait"
+                )
+            }
+            ReflectError::UnsupportedNumericType => {
+                write!(f, "The field is not a supported numeric type")
+            }
+            ReflectError::NotAStruct => write!(f, "The value is not a struct"),
+            ReflectError::VariantNotFound(name) => write!(f, "Enum variant not found: {name}"),
+            ReflectError::NoAccess => write!(f, "Access denied"),
+            ReflectError::NumericOutOfRange => {
+                write!(f, "The value is out of range for the field's numeric type")
+            }
+            ReflectError::ArrayLengthMismatch { expected, actual } => write!(
+                f,
+                "Expected {expected} values for the array, but got {actual}"
+            ),
+            ReflectError::AmbiguousEntity => write!(f, "More than one entity matched"),
+            ReflectError::TypeDataMismatch { expected, found } => {
+                write!(f, "Expected {expected} but only {found} is registered")
+            }
+            ReflectError::NotAMap => write!(f, "The value is not a map"),
+            ReflectError::MapKeyNotFound(key) => write!(f, "Map key not found: {key}"),
+            ReflectError::ValidationFailed => {
+                write!(f, "The value failed validation and was rolled back")
+            }
+            ReflectError::RollbackFailed(err) => write!(
+                f,
+                "The value failed validation, but could not be rolled back: {err}"
+            ),
+            ReflectError::VariantFieldNotFound { variant, field } => {
+                write!(
+                    f,
+                    "The active variant `{variant}` has no field named `{field}`"
+                )
+            }
         }
     }
 }
+
+impl From<ron::Error> for ReflectError {
+    /// Converts a RON serialization error. Errors while de-serializing are converted from `ron::error::SpannedError`
+    /// instead, so they keep their position.
+    fn from(err: ron::Error) -> Self {
+        ReflectError::Serialize(err.to_string())
+    }
+}
+
+impl From<ron::error::SpannedError> for ReflectError {
+    /// Converts a RON de-serialization error, keeping its `line:column` position at the start of the message.
+    fn from(err: ron::error::SpannedError) -> Self {
+        ReflectError::Deserialize(err.to_string())
+    }
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Snapshot of a target field for driving an editor widget, such as a text field in an inspector.
+///
+/// This is UI-agnostic glue: capture the state, let the widget edit `serialized` (or pick a control based on `kind`),
+/// then commit the edit with [`apply_widget_edit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectWidgetState {
+    /// The target the state was captured from.
+    pub target: ReflectTarget,
+    /// The reflect kind of the field, used to pick a widget.
+    pub kind: FieldKind,
+    /// The serialized value of the field when it was captured.
+    pub serialized: String,
+}
+
+impl ReflectWidgetState {
+    /// Captures the current serialized value and kind of the target field.
+    pub fn capture(world: &World, target: &ReflectTarget) -> Result<Self, ReflectError> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let serialized = target.with_field(world, |field| {
+            serialize_reflect_value(&type_registry, field)
+        })??;
+        Ok(Self {
+            target: target.clone(),
+            kind: target.field_reflect_kind(world)?,
+            serialized,
+        })
+    }
+}
+
+/// Commits a serialized value edited by a widget to the target field.
+///
+/// The value is validated with [`ReflectTarget::validate_serialized`] first, so an invalid edit leaves the field
+/// unchanged.
+pub fn apply_widget_edit(
+    world: &mut World,
+    target: &ReflectTarget,
+    edited_serialized: &str,
+) -> ReflectSetResult {
+    target.validate_serialized(world, edited_serialized)?;
+    target.set_value_serialized(world, edited_serialized)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+
+        world
+    }
+
+    #[test]
+    fn widget_edit_round_trip_works() {
+        let mut world = create_world();
+        world.insert_resource(ResourceA { value: 1 });
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+
+        // Capture the state, edit the serialized value and apply it
+        let mut state = ReflectWidgetState::capture(&world, &target).unwrap();
+        assert_eq!(state.kind, FieldKind::Value(TypeId::of::<i32>()));
+        assert_eq!(state.serialized, "{\"i32\":1}");
+        state.serialized = state.serialized.replace('1', "5");
+        assert_eq!(
+            apply_widget_edit(&mut world, &state.target, &state.serialized),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.resource::<ResourceA>().value, 5);
+
+        // Test a captured state reflects the new value
+        let state = ReflectWidgetState::capture(&world, &target).unwrap();
+        assert_eq!(state.serialized, "{\"i32\":5}");
+
+        // Test an invalid edit leaves the field unchanged
+        assert_eq!(
+            apply_widget_edit(&mut world, &target, "{\"u32\":7}"),
+            Err(ReflectError::InvalidDowncast)
+        );
+        assert_eq!(world.resource::<ResourceA>().value, 5);
+    }
+}
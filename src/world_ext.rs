@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Extension trait for [`World`] that operates on a [`ReflectTarget`].
+///
+/// ```ignore
+/// world.reflect_set(&target, 5_i32)?;
+/// let value: i32 = world.reflect_read(&target)?;
+/// ```
+pub trait ReflectUtilsWorldExt {
+    /// Reads the value of the target field. See [`ReflectTarget::read_value`].
+    fn reflect_read<T: Reflect + Clone>(
+        &mut self,
+        target: &ReflectTarget,
+    ) -> Result<T, ReflectError>;
+
+    /// Sets the value of the target field. See [`ReflectTarget::set_value`].
+    fn reflect_set<T: Reflect>(&mut self, target: &ReflectTarget, value: T) -> ReflectSetResult;
+
+    /// Toggles the enum variant of the target field. See [`ReflectTarget::toggle_reflect_enum`].
+    fn reflect_toggle_enum(
+        &mut self,
+        target: &ReflectTarget,
+        direction: EnumDirection,
+    ) -> ReflectSetResult;
+}
+
+impl ReflectUtilsWorldExt for World {
+    fn reflect_read<T: Reflect + Clone>(
+        &mut self,
+        target: &ReflectTarget,
+    ) -> Result<T, ReflectError> {
+        target.read_value(self)
+    }
+
+    fn reflect_set<T: Reflect>(&mut self, target: &ReflectTarget, value: T) -> ReflectSetResult {
+        target.set_value(self, value)
+    }
+
+    fn reflect_toggle_enum(
+        &mut self,
+        target: &ReflectTarget,
+        direction: EnumDirection,
+    ) -> ReflectSetResult {
+        target.toggle_reflect_enum(self, direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Default, Clone, PartialEq, Eq, Debug)]
+    enum EnumA {
+        #[default]
+        A,
+        B,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA {
+        value: i32,
+        mode: EnumA,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA {
+        value: i32,
+        mode: EnumA,
+    }
+
+    /// Test utility that creates a new world and registers the test types
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+
+        let type_registry = world.resource_mut::<AppTypeRegistry>();
+        type_registry.write().register::<ResourceA>();
+        type_registry.write().register::<ComponentA>();
+        type_registry.write().register::<EnumA>();
+
+        world
+    }
+
+    #[test]
+    fn resource_extension_methods_work() {
+        let mut world = create_world();
+        world.init_resource::<ResourceA>();
+
+        let target = ReflectTarget::new_resource::<ResourceA>("value");
+        assert_eq!(
+            world.reflect_set(&target, 5_i32),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.reflect_read::<i32>(&target), Ok(5));
+
+        let target = ReflectTarget::new_resource::<ResourceA>("mode");
+        assert_eq!(
+            world.reflect_toggle_enum(&target, EnumDirection::Forward),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.reflect_read::<EnumA>(&target), Ok(EnumA::B));
+    }
+
+    #[test]
+    fn component_extension_methods_work() {
+        let mut world = create_world();
+        let entity = world.spawn(ComponentA::default()).id();
+
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "value");
+        assert_eq!(
+            world.reflect_set(&target, 5_i32),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.reflect_read::<i32>(&target), Ok(5));
+
+        let target = ReflectTarget::new_component::<ComponentA>(entity, "mode");
+        assert_eq!(
+            world.reflect_toggle_enum(&target, EnumDirection::Forward),
+            Ok(ReflectSetSuccess::Changed)
+        );
+        assert_eq!(world.reflect_read::<EnumA>(&target), Ok(EnumA::B));
+
+        // Test the error when the entity does not exist
+        world.despawn(entity);
+        assert_eq!(
+            world.reflect_read::<EnumA>(&target),
+            Err(ReflectError::EntityNotFound)
+        );
+    }
+}